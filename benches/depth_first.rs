@@ -0,0 +1,57 @@
+//! Depth first traversal: ordered vs. double ended
+//!
+//! This benchmark compares the time required for an ordered depth first
+//! traversal of a tree via `depth_first`, which collects the children of each
+//! node into a `Vec`, and via `depth_first_double_ended`, which traverses the
+//! slice iterators over the children in reverse instead.
+
+use std::time::{Duration, Instant};
+
+use transiter::IntoTransIter;
+
+
+/// Tree node
+struct Node {
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// Create a complete tree with the given fanout and depth
+    pub fn complete(fanout: usize, depth: usize) -> Self {
+        let children = if depth > 0 {
+            (0..fanout).map(|_| Self::complete(fanout, depth - 1)).collect()
+        } else {
+            Default::default()
+        };
+        Self {children}
+    }
+}
+
+
+/// Measure the minimum time of a number of runs of the given function
+fn measure(runs: usize, mut f: impl FnMut() -> usize) -> (Duration, usize) {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            let count = std::hint::black_box(f());
+            (start.elapsed(), count)
+        })
+        .min()
+        .expect("No runs")
+}
+
+
+fn main() {
+    let root = Node::complete(8, 6);
+    let runs = 10;
+
+    let (ordered, count) = measure(runs, || {
+        (&root).trans_iter_with(|n: &&Node| n.children.iter()).depth_first().count()
+    });
+    println!("depth_first:              {:?} for {} nodes", ordered, count);
+
+    let (double_ended, count) = measure(runs, || {
+        (&root).trans_iter_with(|n: &&Node| n.children.iter()).depth_first_double_ended().count()
+    });
+    println!("depth_first_double_ended: {:?} for {} nodes", double_ended, count);
+}
@@ -0,0 +1,127 @@
+//! Shortest path: A* search on a grid
+//!
+//! This example demonstrates the implementation of an A* search using a
+//! `BestFirst` iterator. The algorithm operates on a grid in which we may move
+//! to any horizontally or vertically adjacent cell which is not a wall. The
+//! heuristic used for guiding the search is the manhattan distance to the goal.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt;
+
+
+const GRID: &[&str] = &[
+    "S..#....",
+    ".#.#.##.",
+    ".#...#..",
+    ".####.#.",
+    "......#G",
+];
+
+
+/// Position on the grid
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Pos(i32, i32);
+
+impl Pos {
+    /// Retrieve the manhattan distance to another position
+    pub fn distance(&self, other: &Self) -> u32 {
+        ((other.0 - self.0).abs() + (other.1 - self.1).abs()) as u32
+    }
+
+    /// Retrieve all adjacent positions which are not a wall
+    pub fn neighbours(&self) -> impl Iterator<Item = Self> {
+        let Pos(x, y) = *self;
+        vec![Pos(x - 1, y), Pos(x + 1, y), Pos(x, y - 1), Pos(x, y + 1)]
+            .into_iter()
+            .filter(|p| cell(p).map(|c| c != '#').unwrap_or(false))
+    }
+}
+
+/// Retrieve the cell at the given position
+fn cell(pos: &Pos) -> Option<char> {
+    if pos.0 < 0 || pos.1 < 0 {
+        return None
+    }
+    GRID.get(pos.1 as usize).and_then(|row| row.chars().nth(pos.0 as usize))
+}
+
+/// Find the first position of the given cell
+fn find(c: char) -> Pos {
+    GRID.iter()
+        .enumerate()
+        .find_map(|(y, row)| row.find(c).map(|x| Pos(x as i32, y as i32)))
+        .expect("Could not find cell")
+}
+
+
+/// Path
+#[derive(Clone)]
+struct Path {
+    data: Vec<Pos>
+}
+
+impl Path {
+    /// Create a new path with a starting [Pos]
+    pub fn new(first: Pos) -> Self {
+        Self {data: vec![first]}
+    }
+
+    /// Retrieve the last/current [Pos]
+    pub fn last(&self) -> Pos {
+        *self.data.last().unwrap()
+    }
+
+    /// Create a version of this path extended with the given [Pos]
+    pub fn with(&self, next: Pos) -> Self {
+        let mut data = self.data.clone();
+        data.push(next);
+        Self {data}
+    }
+
+    /// Retrieve the cost of this path, i.e. the number of moves
+    pub fn cost(&self) -> u32 {
+        self.data.len() as u32 - 1
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.data.iter().try_for_each(|p| write!(f, "({},{})", p.0, p.1))
+    }
+}
+
+
+fn main() {
+    use transiter::IntoTransIter;
+
+    let start = find('S');
+    let goal = find('G');
+
+    let mut visited = HashSet::new();
+
+    // We are looking for the path from 'S' to 'G' with the minimum number of
+    // moves. We do so by using a `BestFirst` iterator over `Path`s with a
+    // recursion function which extends the given path with a position adjacent
+    // to the last one. The iterator will always yield the path with the lowest
+    // estimated total cost, i.e. the cost of the path itself plus the distance
+    // remaining. Since that distance never overestimates the actual remaining
+    // cost, the first path reaching the goal will be a shortest one.
+    let path = Path::new(start)
+        .best_first_with(
+            move |path: &Path| {
+                let current = path.last();
+                if visited.insert(current) {
+                    current.neighbours().filter(|p| !visited.contains(p)).map(|p| path.with(p)).collect()
+                } else {
+                    Vec::new()
+                }
+            },
+            |path| Reverse(path.cost() + path.last().distance(&goal)),
+        )
+        .inspect(|path| eprintln!("{} {}", path, path.cost()))
+        .find(|path| path.last() == goal)
+        .expect("Could not find path");
+
+    println!("S->G: {}, cost: {}", path, path.cost());
+}
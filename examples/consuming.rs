@@ -0,0 +1,46 @@
+//! Consuming traversal
+//!
+//! This example demonstrates a traversal in which the recursion function takes
+//! items by value. A text is split into words by repeatedly splitting strings
+//! in two at a whitespace near their middle. The first half of each string
+//! retains that string's buffer and only the second half is moved into a new
+//! one. Thus, no part of the text is ever cloned while descending.
+
+use transiter::IntoTransIter;
+
+
+/// Split the given text in two at a whitespace near its middle
+///
+/// If the text does not contain any whitespace, it is returned as is.
+fn split_middle(mut text: String) -> Result<(String, String), String> {
+    let mid = (text.len() / 2..).find(|i| text.is_char_boundary(*i)).unwrap_or(text.len());
+    let pos = text[mid..]
+        .find(char::is_whitespace)
+        .map(|pos| pos + mid)
+        .or_else(|| text[..mid].rfind(char::is_whitespace));
+    if let Some(pos) = pos {
+        let len = text[pos..].chars().next().map(char::len_utf8).unwrap_or_default();
+        let tail = text.split_off(pos + len);
+        text.truncate(pos);
+        Ok((text, tail))
+    } else {
+        Err(text)
+    }
+}
+
+
+fn main() {
+    let text = "Zwölf Boxkämpfer jagen Viktor quer über den großen Sylter Deich";
+
+    let words: Vec<_> = text
+        .to_owned()
+        .trans_iter_consuming_with(|text| match split_middle(text) {
+            Ok((head, tail))    => (None, vec![head, tail]),
+            Err(word)           => (Some(word).filter(|w| !w.is_empty()), Vec::new()),
+        })
+        .depth_first()
+        .collect();
+
+    words.iter().for_each(|word| println!("{}", word));
+    assert_eq!(words.join(" "), text);
+}
@@ -44,7 +44,7 @@ impl Path {
 
     /// Retrieve the last/current [Node]
     pub fn last(&self) -> Node {
-        self.data.last().unwrap().clone()
+        *self.data.last().unwrap()
     }
 
     /// Create a version of this path extended with the given [Node]
@@ -44,7 +44,7 @@ impl Path {
 
     /// Retrieve the last/current [Node]
     pub fn last(&self) -> Node {
-        self.data.last().unwrap().clone()
+        *self.data.last().unwrap()
     }
 
     /// Create a version of this path extended with the given [Node]
@@ -74,7 +74,7 @@ impl Ord for Path {
 
 impl PartialOrd for Path {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        PartialOrd::partial_cmp(&other.len(), &self.len())
+        Some(self.cmp(other))
     }
 }
 
@@ -0,0 +1,61 @@
+//! Traversal by reference
+//!
+//! This example demonstrates walking an in-memory tree without cloning any of
+//! its nodes. The `AutoTransIter` trait is implemented for references to the
+//! node type, which makes `trans_iter_ref` available for nodes. The resulting
+//! `TransIter` yields references into the tree.
+
+use transiter::{AutoTransIter, AutoTransIterRef, Event};
+
+
+/// Directory tree entry
+struct Entry {
+    name: &'static str,
+    children: Vec<Entry>,
+}
+
+impl Entry {
+    /// Create a new entry without children
+    pub fn file(name: &'static str) -> Self {
+        Self {name, children: Default::default()}
+    }
+
+    /// Create a new entry with the given children
+    pub fn dir(name: &'static str, children: Vec<Entry>) -> Self {
+        Self {name, children}
+    }
+}
+
+impl<'a> AutoTransIter<&'a Entry> for &'a Entry {
+    type RecIter = std::slice::Iter<'a, Entry>;
+
+    fn recurse(item: &&'a Entry) -> Self::RecIter {
+        item.children.iter()
+    }
+}
+
+
+fn main() {
+    let root = Entry::dir("/", vec![
+        Entry::dir("etc", vec![Entry::file("hosts"), Entry::file("passwd")]),
+        Entry::dir("usr", vec![
+            Entry::dir("bin", vec![Entry::file("cargo"), Entry::file("rustc")]),
+            Entry::dir("lib", Default::default()),
+        ]),
+        Entry::file("vmlinuz"),
+    ]);
+
+    // The iterator only holds references, so cloning it is cheap. We use a
+    // clone for counting the entries before printing them.
+    let iter = root.trans_iter_ref();
+    println!("{} entries", iter.clone().count());
+
+    let mut depth = 0;
+    iter.events().for_each(|event| match event {
+        Event::Enter(entry) => {
+            println!("{:indent$}{}", "", entry.name, indent = 2 * depth);
+            depth += 1;
+        },
+        Event::Leave(_) => depth -= 1,
+    });
+}
@@ -0,0 +1,143 @@
+//! Best first traversal guided by a heuristic
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+
+/// Transitive best first iterator
+///
+/// This iterator yields all elements which are transitively reachable from an
+/// initial set of items through a given recursion function, including those
+/// initial items. Items discovered through a call to the recursion function
+/// will be enqueued and only yielded after the item passed in that call. I.e.
+/// if the recursion function yields the "children" of a node, a node will only
+/// be yielded after its "parent".
+///
+/// Of the currently enqueued items, the iterator will always yield the one for
+/// which a given heuristic function yields the greatest key. Thus, unlike
+/// [TransPrioQueue](crate::TransPrioQueue), this iterator does not require the
+/// item type to implement [Ord]. The heuristic is called exactly once for each
+/// item, when it is enqueued.
+///
+/// Note that the iterator itself will not filter items which are reachable via
+/// multiple paths. Generally, this iterator is not suitable for navigating
+/// potentially cyclic structures on its own. For such structures, consider
+/// implementing the necessary filtering in the recursion function supplied
+/// during iterator creation.
+///
+/// # Example
+///
+/// ```
+/// use std::cmp::Reverse;
+///
+/// let lengths: Vec<_> = transiter::BestFirst::new(
+///     String::from("abc"),
+///     |s| (0..s.len()).map(|i| [&s[..i], &s[i + 1..]].concat()).collect::<Vec<_>>(),
+///     |s| Reverse(s.len()),
+/// ).map(|s| s.len()).take(4).collect();
+/// assert_eq!(lengths, vec![3, 2, 1, 0]);
+/// ```
+#[derive(Debug)]
+pub struct BestFirst<F, I, T, G, K>
+where F: FnMut(&T) -> I,
+      I: IntoIterator<Item = T>,
+      G: FnMut(&T) -> K,
+      K: Ord,
+{
+    get_next: F,
+    get_key: G,
+    data: BinaryHeap<Keyed<K, T>>,
+}
+
+impl<F, I, T, G, K> BestFirst<F, I, T, G, K>
+where F: FnMut(&T) -> I,
+      I: IntoIterator<Item = T>,
+      G: FnMut(&T) -> K,
+      K: Ord,
+{
+    /// Create a new transitive best first iterator
+    ///
+    /// The iterator will yield all elements which are transitively reachable
+    /// from the `initial` item through the given `recursion` function,
+    /// including the `initial` itself, in an order guided by the given
+    /// `heuristic`.
+    pub fn new(initial: T, recursion: F, heuristic: G) -> Self {
+        Self::new_multi(std::iter::once(initial), recursion, heuristic)
+    }
+
+    /// Create a new transitive best first iterator with multiple initial items
+    ///
+    /// The iterator will yield all elements which are transitively reachable
+    /// from the `initial` set of items through the given `recursion` function,
+    /// including the items in the initial set, in an order guided by the given
+    /// `heuristic`.
+    pub fn new_multi(initial: impl IntoIterator<Item = T>, recursion: F, mut heuristic: G) -> Self {
+        let data = initial.into_iter().map(|item| Keyed::new(item, &mut heuristic)).collect();
+        Self {get_next: recursion, get_key: heuristic, data}
+    }
+}
+
+impl<F, I, T, G, K> Clone for BestFirst<F, I, T, G, K>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      T: Clone,
+      G: FnMut(&T) -> K + Clone,
+      K: Ord + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {get_next: self.get_next.clone(), get_key: self.get_key.clone(), data: self.data.clone()}
+    }
+}
+
+impl<F, I, T, G, K> Iterator for BestFirst<F, I, T, G, K>
+where F: FnMut(&T) -> I,
+      I: IntoIterator<Item = T>,
+      G: FnMut(&T) -> K,
+      K: Ord,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let res = self.data.pop().map(|k| k.item);
+        if let Some(items) = res.as_ref().map(&mut self.get_next) {
+            let get_key = &mut self.get_key;
+            self.data.extend(items.into_iter().map(|item| Keyed::new(item, &mut *get_key)))
+        }
+        res
+    }
+}
+
+
+/// Item associated with a key, ordered only by that key
+#[derive(Clone, Debug)]
+struct Keyed<K: Ord, T> {
+    key: K,
+    item: T,
+}
+
+impl<K: Ord, T> Keyed<K, T> {
+    /// Associate the given item with the key computed by the given function
+    pub fn new(item: T, get_key: impl FnOnce(&T) -> K) -> Self {
+        Self {key: get_key(&item), item}
+    }
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
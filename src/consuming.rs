@@ -0,0 +1,150 @@
+//! Transitive iteration with a recursion function consuming items
+
+use std::collections::VecDeque;
+
+use super::{FrontierState, TraversalOrder};
+
+
+/// Transitive iterator with a recursion function consuming items
+///
+/// Unlike [TransIter](crate::TransIter), this iterator passes items to the
+/// recursion function by value rather than by reference. This allows moving
+/// (parts of) an item into the items derived from it, e.g. its "children",
+/// without cloning. Since the item is consumed by the recursion function, the
+/// iterator cannot yield it afterwards. Instead, the recursion function
+/// returns the value to yield alongside the items to enqueue.
+///
+/// The iterator calls the recursion function for an item when it would yield
+/// that item, i.e. in the order a [TransIter](crate::TransIter) with the same
+/// mode would yield the items. If the recursion function returns `Some`
+/// value, the iterator yields that value. If it returns `None`, the iterator
+/// yields nothing for the item and proceeds with the next item. In both cases,
+/// the items returned alongside are enqueued according to the mode. Thus, an
+/// item yielding `None` does not prevent items derived from it from being
+/// yielded. Note that a call to [next](Iterator::next) may thus result in
+/// multiple calls to the recursion function.
+///
+/// Note that the iterator itself will not filter items which are reachable via
+/// multiple paths. Generally, this iterator is not suitable for navigating
+/// potentially cyclic structures on its own.
+///
+/// # Example
+///
+/// ```
+/// use transiter::IntoTransIter;
+///
+/// let words: Vec<_> = String::from("split me up")
+///     .trans_iter_consuming_with(|mut s| match s.find(' ') {
+///         Some(pos) => { let rest = s.split_off(pos + 1); s.pop(); (None, vec![s, rest]) },
+///         None => (Some(s), Vec::new()),
+///     })
+///     .depth_first()
+///     .collect();
+/// assert_eq!(words, vec!["split", "me", "up"]);
+/// ```
+#[derive(Debug)]
+pub struct Consuming<F: FnMut(T) -> (Option<T>, I), I: IntoIterator<Item = T>, T> {
+    get_next: F,
+    queue: VecDeque<T>,
+    mode: TraversalOrder,
+}
+
+impl<F: FnMut(T) -> (Option<T>, I), I: IntoIterator<Item = T>, T> Consuming<F, I, T> {
+    /// Create a new consuming transitive iterator
+    ///
+    /// The iterator will yield the values the given `recursion` function
+    /// returns for the `initial` item and all items transitively reachable
+    /// from it.
+    pub fn new(initial: T, recursion: F) -> Self {
+        Self::new_multi(std::iter::once(initial), recursion)
+    }
+
+    /// Create a new consuming transitive iterator with multiple initial items
+    ///
+    /// The iterator will yield the values the given `recursion` function
+    /// returns for the items in the `initial` set and all items transitively
+    /// reachable from them.
+    pub fn new_multi(initial: impl IntoIterator<Item = T>, recursion: F) -> Self {
+        Self {get_next: recursion, queue: initial.into_iter().collect(), mode: Default::default()}
+    }
+
+    /// Create a new consuming transitive iterator from a snapshot
+    ///
+    /// The iterator will start with the items and the mode of the given
+    /// `state`.
+    pub(crate) fn from_state(state: FrontierState<T>, recursion: F) -> Self {
+        Self {get_next: recursion, queue: state.queue, mode: state.mode}
+    }
+
+    /// Make this iterator iterate breadth first
+    ///
+    /// See [TransIter::breadth_first](crate::TransIter::breadth_first) for
+    /// details.
+    pub fn breadth_first(self) -> Self {
+        Self {mode: TraversalOrder::BreadthFirst, ..self}
+    }
+
+    /// Make this iterator iterate depth first
+    ///
+    /// See [TransIter::depth_first](crate::TransIter::depth_first) for details.
+    pub fn depth_first(self) -> Self {
+        Self {mode: TraversalOrder::DepthFirst, ..self}
+    }
+
+    /// Make this iterator iterate depth first, without preserving sibling order
+    ///
+    /// See [TransIter::depth_first_unordered](crate::TransIter::depth_first_unordered)
+    /// for details.
+    pub fn depth_first_unordered(self) -> Self {
+        Self {mode: TraversalOrder::DepthFirstUnordered, ..self}
+    }
+
+    /// Make this iterator iterate in the given order
+    ///
+    /// See [TransIter::ordered](crate::TransIter::ordered) for details.
+    pub fn ordered(self, order: TraversalOrder) -> Self {
+        Self {mode: order, ..self}
+    }
+
+    /// Retrieve the current frontier
+    ///
+    /// The returned iterator yields references to the items currently
+    /// enqueued, i.e. items which were not yet passed to the recursion
+    /// function.
+    pub fn frontier(&self) -> impl Iterator<Item = &T> {
+        self.queue.iter()
+    }
+
+    /// Check whether this iterator is exhausted
+    ///
+    /// Returns `true` if no items are enqueued. Note that an iterator which is
+    /// not exhausted may still not yield any further values if the recursion
+    /// function returns `None` for all remaining items.
+    pub fn is_exhausted(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl<F, I, T> Clone for Consuming<F, I, T>
+where F: FnMut(T) -> (Option<T>, I) + Clone,
+      I: IntoIterator<Item = T>,
+      T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {get_next: self.get_next.clone(), queue: self.queue.clone(), mode: self.mode}
+    }
+}
+
+impl<F: FnMut(T) -> (Option<T>, I), I: IntoIterator<Item = T>, T> Iterator for Consuming<F, I, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let (res, items) = (self.get_next)(self.queue.pop_front()?);
+            self.mode.enqueue(&mut self.queue, items);
+            if res.is_some() {
+                return res
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Traversal with recursion functions able to end the traversal
+
+use super::{TransIter, TraversalOrder};
+
+
+/// Result of a recursion function used with a [Controlled] iterator
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Recurse<I> {
+    /// The "children" of the item passed to the recursion function
+    Children(I),
+    /// End the traversal after yielding the item passed to the recursion
+    /// function
+    Stop,
+}
+
+impl<I: IntoIterator> IntoIterator for Recurse<I> {
+    type Item = I::Item;
+    type IntoIter = std::iter::Flatten<std::option::IntoIter<I>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::Children(items)   => Some(items),
+            Self::Stop              => None,
+        }.into_iter().flatten()
+    }
+}
+
+
+/// Transitive iterator with a recursion function able to end the traversal
+///
+/// This iterator behaves like a [TransIter] whose recursion function returns
+/// a [Recurse] rather than an iterator directly. If the recursion function
+/// returns [Recurse::Children], the items are enqueued as usual. If it returns
+/// [Recurse::Stop], the item passed to the recursion function is still
+/// yielded, but all items currently enqueued are discarded. Thus, the iterator
+/// will not yield any further items.
+///
+/// # Example
+///
+/// ```
+/// use transiter::{IntoTransIter, Recurse};
+///
+/// let mut budget = 4;
+/// let items: Vec<_> = 1u8
+///     .trans_iter_with_control(|n| if budget > 0 {
+///         budget -= 1;
+///         Recurse::Children(vec![2 * n, 2 * n + 1])
+///     } else {
+///         Recurse::Stop
+///     })
+///     .collect();
+/// assert_eq!(items, vec![1, 2, 3, 4, 5]);
+/// ```
+#[derive(Debug)]
+pub struct Controlled<F: FnMut(&T) -> Recurse<I>, I: IntoIterator<Item = T>, T> {
+    inner: TransIter<F, Recurse<I>, T>,
+}
+
+impl<F: FnMut(&T) -> Recurse<I>, I: IntoIterator<Item = T>, T> Controlled<F, I, T> {
+    /// Create a new controlled transitive iterator
+    ///
+    /// The iterator will yield all elements which are transitively reachable
+    /// from the `initial` item through the given `recursion` function,
+    /// including the `initial` itself, until the `recursion` function returns
+    /// [Recurse::Stop].
+    pub fn new(initial: T, recursion: F) -> Self {
+        TransIter::new(initial, recursion).into()
+    }
+
+    /// Create a new controlled transitive iterator with multiple initial items
+    ///
+    /// The iterator will yield all elements which are transitively reachable
+    /// from the `initial` set of items through the given `recursion` function,
+    /// including the items in the initial set, until the `recursion` function
+    /// returns [Recurse::Stop].
+    pub fn new_multi(initial: impl IntoIterator<Item = T>, recursion: F) -> Self {
+        TransIter::new_multi(initial, recursion).into()
+    }
+
+    /// Make this iterator iterate breadth first
+    ///
+    /// See [TransIter::breadth_first] for details.
+    pub fn breadth_first(self) -> Self {
+        Self {inner: self.inner.breadth_first()}
+    }
+
+    /// Make this iterator iterate depth first
+    ///
+    /// See [TransIter::depth_first] for details.
+    pub fn depth_first(self) -> Self {
+        Self {inner: self.inner.depth_first()}
+    }
+
+    /// Make this iterator iterate depth first, without preserving sibling order
+    ///
+    /// See [TransIter::depth_first_unordered] for details.
+    pub fn depth_first_unordered(self) -> Self {
+        Self {inner: self.inner.depth_first_unordered()}
+    }
+
+    /// Make this iterator iterate in the given order
+    ///
+    /// See [TransIter::ordered] for details.
+    pub fn ordered(self, order: TraversalOrder) -> Self {
+        Self {inner: self.inner.ordered(order)}
+    }
+
+    /// Retrieve the current frontier
+    ///
+    /// See [TransIter::frontier] for details.
+    pub fn frontier(&self) -> impl Iterator<Item = &T> {
+        self.inner.frontier()
+    }
+
+    /// Check whether this iterator is exhausted
+    ///
+    /// See [TransIter::is_exhausted] for details.
+    pub fn is_exhausted(&self) -> bool {
+        self.inner.is_exhausted()
+    }
+}
+
+impl<F, I, T> Clone for Controlled<F, I, T>
+where F: FnMut(&T) -> Recurse<I> + Clone,
+      I: IntoIterator<Item = T>,
+      T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {inner: self.inner.clone()}
+    }
+}
+
+impl<F: FnMut(&T) -> Recurse<I>, I: IntoIterator<Item = T>, T> From<TransIter<F, Recurse<I>, T>> for Controlled<F, I, T> {
+    fn from(inner: TransIter<F, Recurse<I>, T>) -> Self {
+        Self {inner}
+    }
+}
+
+impl<F: FnMut(&T) -> Recurse<I>, I: IntoIterator<Item = T>, T> Iterator for Controlled<F, I, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let res = self.inner.queue.pop_front();
+        match res.as_ref().map(&mut self.inner.get_next) {
+            Some(Recurse::Children(items))  => self.inner.enqueue(items),
+            Some(Recurse::Stop)             => self.inner.queue.clear(),
+            None                            => (),
+        }
+        res
+    }
+}
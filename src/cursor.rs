@@ -0,0 +1,73 @@
+//! Tracking of the item yielded last
+
+use super::TransIter;
+
+
+/// Transitive iterator keeping track of the item yielded last
+///
+/// This iterator is created via [TransIter::with_cursor]. It yields the same
+/// items the underlying [TransIter] would, but retains a copy of the item
+/// yielded last, which may be retrieved via [cursor](WithCursor::cursor). This
+/// allows e.g. highlighting the current position during a step-by-step
+/// traversal.
+///
+/// # Example
+///
+/// ```
+/// use transiter::IntoTransIter;
+///
+/// let mut iter = 1u8.trans_iter_with(|n| if *n < 3 { vec![n + 1] } else { Vec::new() }).with_cursor();
+/// assert_eq!(iter.cursor(), None);
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.cursor(), Some(&1));
+/// assert_eq!(iter.next(), Some(2));
+/// assert_eq!(iter.cursor(), Some(&2));
+/// ```
+#[derive(Debug)]
+pub struct WithCursor<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Clone> {
+    inner: TransIter<F, I, T>,
+    cursor: Option<T>,
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Clone> WithCursor<F, I, T> {
+    /// Create a new iterator tracking the item yielded last
+    pub(crate) fn new(inner: TransIter<F, I, T>) -> Self {
+        Self {inner, cursor: None}
+    }
+
+    /// Retrieve the item yielded last
+    ///
+    /// Before the first call to [next](Iterator::next), this function returns
+    /// `None`. Once the iterator is exhausted, it keeps returning the last
+    /// item yielded.
+    pub fn cursor(&self) -> Option<&T> {
+        self.cursor.as_ref()
+    }
+
+    /// Retrieve the underlying [TransIter]
+    pub fn into_inner(self) -> TransIter<F, I, T> {
+        self.inner
+    }
+}
+
+impl<F, I, T> Clone for WithCursor<F, I, T>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {inner: self.inner.clone(), cursor: self.cursor.clone()}
+    }
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Clone> Iterator for WithCursor<F, I, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let res = self.inner.next();
+        if let Some(item) = res.as_ref() {
+            self.cursor = Some(item.clone())
+        }
+        res
+    }
+}
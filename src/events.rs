@@ -0,0 +1,84 @@
+//! Enter/leave events for depth first traversal
+
+use std::collections::VecDeque;
+
+use super::TransIter;
+
+
+/// Traversal event
+///
+/// An [Events] iterator yields an `Enter` event for an item before any of the
+/// events related to the items reachable from it. After those, it yields a
+/// `Leave` event for the same item.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Event<T> {
+    /// The traversal enters an item, i.e. descends into its "children"
+    Enter(T),
+    /// The traversal leaves an item, i.e. all its "children" were visited
+    Leave(T),
+}
+
+impl<T> Event<T> {
+    /// Retrieve the item this event refers to
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Enter(item) => item,
+            Self::Leave(item) => item,
+        }
+    }
+}
+
+
+/// Iterator yielding [Event]s in depth first order
+///
+/// This iterator is created via [TransIter::events]. It traverses the items
+/// in depth first order, preserving the order of siblings, and yields an
+/// [Event::Enter] when descending into an item and an [Event::Leave] after all
+/// items reachable from that item were visited.
+///
+/// The recursion function is called for an item when the item is entered.
+/// Only the iterators returned by the recursion function for the items
+/// currently entered are retained, i.e. children are not collected eagerly.
+#[derive(Debug)]
+pub struct Events<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Clone> {
+    get_next: F,
+    roots: VecDeque<T>,
+    stack: Vec<(T, I::IntoIter)>,
+}
+
+impl<F, I, T> Clone for Events<F, I, T>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      I::IntoIter: Clone,
+      T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {get_next: self.get_next.clone(), roots: self.roots.clone(), stack: self.stack.clone()}
+    }
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Clone> From<TransIter<F, I, T>> for Events<F, I, T> {
+    fn from(iter: TransIter<F, I, T>) -> Self {
+        Self {get_next: iter.get_next, roots: iter.queue, stack: Default::default()}
+    }
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Clone> Iterator for Events<F, I, T> {
+    type Item = Event<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match self.stack.last_mut() {
+            Some((_, children)) => children.next(),
+            None                => self.roots.pop_front(),
+        };
+
+        if let Some(item) = next {
+            let children = (self.get_next)(&item).into_iter();
+            let res = item.clone();
+            self.stack.push((item, children));
+            Some(Event::Enter(res))
+        } else {
+            self.stack.pop().map(|(item, _)| Event::Leave(item))
+        }
+    }
+}
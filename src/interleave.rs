@@ -0,0 +1,66 @@
+//! Interleaving of two traversals
+
+use std::iter::Fuse;
+
+
+/// Interleave the items of two iterators
+///
+/// The returned [Interleave] iterator alternates between `a` and `b`, starting
+/// with `a`, yielding one item from each in turn for as long as both yield
+/// items. Once one of them is exhausted, the remaining items of the other one
+/// are yielded.
+///
+/// The iterators may be of different types, e.g. a [TransIter](crate::TransIter)
+/// and a [TransPrioQueue](crate::TransPrioQueue), as long as they yield the same
+/// item type. Note that the interleaving happens on a per-item basis. If two
+/// traversals iterating breadth first are interleaved, the items of the
+/// respective levels will only line up if the levels of both traversals are of
+/// the same size.
+///
+/// # Example
+///
+/// ```
+/// use transiter::IntoTransIter;
+///
+/// let a = 1u8.trans_iter_with(|n| if *n < 3 { vec![n + 1] } else { Vec::new() });
+/// let b = 10u8.trans_iter_with(|n| if *n < 14 { vec![n + 1] } else { Vec::new() });
+/// let items: Vec<_> = transiter::interleave(a, b).collect();
+/// assert_eq!(items, vec![1, 10, 2, 11, 3, 12, 13, 14]);
+/// ```
+pub fn interleave<A, B>(a: A, b: B) -> Interleave<A::IntoIter, B::IntoIter>
+where A: IntoIterator,
+      B: IntoIterator<Item = A::Item>,
+{
+    Interleave {a: a.into_iter().fuse(), b: b.into_iter().fuse(), b_next: false}
+}
+
+
+/// Iterator interleaving the items of two iterators
+///
+/// This iterator is created via [interleave].
+#[derive(Clone, Debug)]
+pub struct Interleave<A: Iterator, B: Iterator<Item = A::Item>> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+    b_next: bool,
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for Interleave<A, B> {
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.b_next = !self.b_next;
+        if self.b_next {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = a_upper.and_then(|a| b_upper.and_then(|b| a.checked_add(b)));
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
@@ -16,10 +16,60 @@
 //! For types with an obvious or inherent relation to associated items, users
 //! may choose to implement the [AutoTransIter] trait. It provides the more
 //! convenient [trans_iter](AutoTransIter::trans_iter) function which does not
-//! require a recursion function to be supplied for each call.
+//! require a recursion function to be supplied for each call. Implementing
+//! [AutoTransIter] for references to a type makes the
+//! [trans_iter_ref](AutoTransIterRef::trans_iter_ref) function available for
+//! values of that type.
+//!
+//! For consumers which need to know not only when an item is reached but also
+//! when all items reachable from it were visited, [TransIter::events] provides
+//! an iterator over [Event]s.
+//!
+//! For items which do not implement [Ord] or which should be visited in an
+//! order defined by some external key, [BestFirst] implements a best first
+//! traversal guided by a heuristic function.
+//!
+//! Recursion functions which may need to end the entire traversal may be used
+//! with a [Controlled] iterator, created via
+//! [trans_iter_with_control](IntoTransIter::trans_iter_with_control).
+//!
+//! The items of two traversals may be combined into a single stream via
+//! [interleave].
+//!
+//! Recursion functions which need to move data from an item into the items
+//! derived from it may be used with a [Consuming] iterator, created via
+//! [trans_iter_consuming_with](IntoTransIter::trans_iter_consuming_with).
+//!
+//! For consumers which need the number of items reachable from each item,
+//! [TransIter::with_subtree_size] yields items along with their subtree sizes.
 
 use std::iter::FromIterator;
 
+mod best_first;
+mod consuming;
+mod control;
+mod cursor;
+mod events;
+mod interleave;
+mod limit;
+mod stable;
+mod state;
+mod subtree;
+
+#[cfg(any(test, feature = "quickcheck"))]
+pub mod testing;
+
+pub use best_first::BestFirst;
+pub use consuming::Consuming;
+pub use control::{Controlled, Recurse};
+pub use cursor::WithCursor;
+pub use events::{Event, Events};
+pub use interleave::{interleave, Interleave};
+pub use limit::Limit;
+pub use stable::StablePrioQueue;
+pub use state::{FrontierState, PrioQueueState};
+pub use subtree::SubtreeSizes;
+
 
 /// Transitive iterator
 ///
@@ -50,11 +100,11 @@ use std::iter::FromIterator;
 /// ).take(10).collect();
 /// assert_eq!(names, vec!["", "a", "b", "c", "aa", "ab", "ac", "ba", "bb", "bc"]);
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct TransIter<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> {
     get_next: F,
     queue: std::collections::VecDeque<T>,
-    mode: Mode,
+    mode: TraversalOrder,
 }
 
 impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> TransIter<F, I, T> {
@@ -76,6 +126,15 @@ impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> TransIter<F, I, T> {
         Self {get_next: recursion, queue: FromIterator::from_iter(initial), mode: Default::default()}
     }
 
+    /// Create a new transitive iterator from a snapshot
+    ///
+    /// The iterator will yield the same items the iterator from which the
+    /// given `state` was taken would have, given the same `recursion`
+    /// function.
+    pub fn resume_with(state: FrontierState<T>, recursion: F) -> Self {
+        Self {get_next: recursion, queue: state.queue, mode: state.mode}
+    }
+
     /// Make this iterator iterate breadth first
     ///
     /// The iterator will yield siblings grouped together, in the order they
@@ -83,7 +142,7 @@ impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> TransIter<F, I, T> {
     ///
     /// This is the default mode.
     pub fn breadth_first(self) -> Self {
-        Self {mode: Mode::BreadthFirst, ..self}
+        Self {mode: TraversalOrder::BreadthFirst, ..self}
     }
 
     /// Make this iterator iterate depth first
@@ -94,9 +153,44 @@ impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> TransIter<F, I, T> {
     /// Siblings will be yielded in the order they were yielded by the
     /// `Iterator` returned by the recursion function. Note that preserving the
     /// order inhibits some additional cost. Consider using
-    /// `depth_first_unordered` instead.
+    /// [depth_first_double_ended](TransIter::depth_first_double_ended) or
+    /// [depth_first_unordered](TransIter::depth_first_unordered) instead.
     pub fn depth_first(self) -> Self {
-        Self {mode: Mode::DepthFirst, ..self}
+        Self {mode: TraversalOrder::DepthFirst, ..self}
+    }
+
+    /// Make this iterator iterate depth first, avoiding intermediate collection
+    ///
+    /// The returned iterator yields the same items in the same order as this
+    /// iterator would after calling [depth_first](TransIter::depth_first).
+    /// However, since the iterators returned by the recursion function are
+    /// double ended, they can be traversed in reverse directly. Thus, the
+    /// items yielded by the recursion function are not collected into an
+    /// intermediate [Vec] for each call.
+    ///
+    /// Note that this is achieved by reversing the iterators returned by the
+    /// recursion function and iterating depth first without preserving sibling
+    /// order. Switching the returned iterator to another mode will thus result
+    /// in siblings being yielded in reverse order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let items: Vec<_> = 1u8
+    ///     .trans_iter_with(|n| if *n < 4 { 2 * n..2 * n + 2 } else { 0..0 })
+    ///     .depth_first_double_ended()
+    ///     .collect();
+    /// assert_eq!(items, vec![1, 2, 4, 5, 3, 6, 7]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn depth_first_double_ended(self) -> TransIter<
+        impl FnMut(&T) -> std::iter::Rev<I::IntoIter>,
+        std::iter::Rev<I::IntoIter>,
+        T,
+    > where I::IntoIter: DoubleEndedIterator {
+        self.map_recursion(|mut get_next| move |item: &T| get_next(item).into_iter().rev()).depth_first_unordered()
     }
 
     /// Make this iterator iterate depth first, without preserving sibling order
@@ -108,15 +202,489 @@ impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> TransIter<F, I, T> {
     /// order they were yielded by the `Iterator` returned by the recursion
     /// function.
     pub fn depth_first_unordered(self) -> Self {
-        Self {mode: Mode::DepthFirstUnordered, ..self}
+        Self {mode: TraversalOrder::DepthFirstUnordered, ..self}
+    }
+
+    /// Make this iterator iterate in the given order
+    ///
+    /// This function allows selecting the mode via a [TraversalOrder] value
+    /// rather than via one of the dedicated functions.
+    pub fn ordered(self, order: TraversalOrder) -> Self {
+        Self {mode: order, ..self}
+    }
+
+    /// Retrieve the current frontier
+    ///
+    /// The returned iterator yields references to the items currently
+    /// enqueued, i.e. items which were not yet yielded and not yet passed to
+    /// the recursion function. The items are yielded in the order this
+    /// iterator would yield them if the recursion function did not yield any
+    /// items.
+    pub fn frontier(&self) -> impl Iterator<Item = &T> {
+        self.queue.iter()
+    }
+
+    /// Check whether this iterator is exhausted
+    ///
+    /// Returns `true` if no items are currently enqueued, i.e. if any further
+    /// call to [next](Iterator::next) will return `None`. Note that this is
+    /// already the case for a freshly created iterator with an empty initial
+    /// set.
+    pub fn is_exhausted(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Take a snapshot of the current frontier
+    ///
+    /// The returned [FrontierState] holds copies of the items currently
+    /// enqueued as well as the mode. It may be used for resuming the traversal
+    /// later via [resume_with](TransIter::resume_with).
+    pub fn frontier_state(&self) -> FrontierState<T> where T: Clone {
+        FrontierState {queue: self.queue.clone(), mode: self.mode}
+    }
+
+    /// Convert this iterator into a snapshot of its frontier
+    ///
+    /// Unlike [frontier_state](TransIter::frontier_state), this function moves
+    /// the items currently enqueued into the snapshot. The recursion function
+    /// is dropped.
+    pub(crate) fn into_state(self) -> FrontierState<T> {
+        FrontierState {queue: self.queue, mode: self.mode}
+    }
+
+    /// Limit the number of items yielded
+    ///
+    /// The returned [Limit] iterator yields at most `limit` items. Unlike
+    /// [Iterator::take], it allows retrieving the frontier and the underlying
+    /// [TransIter] afterwards, enabling resumption of the traversal.
+    pub fn limit(self, limit: usize) -> Limit<F, I, T> {
+        Limit::new(self, limit)
+    }
+
+    /// Keep track of the item yielded last
+    ///
+    /// The returned [WithCursor] iterator yields the same items this iterator
+    /// would, but retains a copy of the item yielded last.
+    pub fn with_cursor(self) -> WithCursor<F, I, T> where T: Clone {
+        WithCursor::new(self)
+    }
+
+    /// Split the traversal at the given depth
+    ///
+    /// Items in the current frontier are considered to be at depth `0`, items
+    /// yielded by the recursion function at the depth of the item passed to it
+    /// plus one. This function collects all items at a depth of `depth` or
+    /// less into a [Vec], in the order this iterator would yield them. The
+    /// recursion function is called for each of those items. The items yielded
+    /// by it for items at `depth` are not yielded but make up the frontier of
+    /// the returned [TransIter], which continues the traversal with the same
+    /// recursion function and mode.
+    ///
+    /// Note that the "near" part is fully materialized while the "far" part
+    /// remains lazy. For breadth first traversal, chaining the two yields the
+    /// same items in the same order as this iterator would. For depth first
+    /// traversal, the far part yields the items below `depth` subtree by
+    /// subtree, in the order the subtrees' roots were discovered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let (near, far) = 1u8
+    ///     .trans_iter_with(|n| if *n < 8 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+    ///     .split_at_depth(1);
+    /// assert_eq!(near, vec![1, 2, 3]);
+    /// assert_eq!(far.frontier().cloned().collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+    /// assert_eq!(far.collect::<Vec<_>>(), vec![4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    /// ```
+    pub fn split_at_depth(self, depth: usize) -> (Vec<T>, Self) {
+        let mut get_next = self.get_next;
+        let mut far = std::collections::VecDeque::new();
+        let near = TransIter {
+            get_next: |(d, item): &(usize, T)| {
+                let children = get_next(item).into_iter();
+                let res = if *d < depth {
+                    Some(std::iter::repeat(d + 1).zip(children))
+                } else {
+                    far.extend(children);
+                    None
+                };
+                res.into_iter().flatten()
+            },
+            queue: self.queue.into_iter().map(|item| (0, item)).collect(),
+            mode: self.mode,
+        }.map(|(_, item)| item).collect();
+        (near, TransIter {get_next, queue: far, mode: self.mode})
+    }
+
+    /// Reset this iterator with a new initial set
+    ///
+    /// All items currently enqueued are discarded and replaced with the items
+    /// in the given `initial` set. The recursion function and the mode are
+    /// retained, as is the memory already allocated for the internal queue.
+    /// Thus, an iterator may be reused for multiple traversals.
+    pub fn reset(&mut self, initial: impl IntoIterator<Item = T>) {
+        self.queue.clear();
+        self.queue.extend(initial)
+    }
+
+    /// Drain this iterator into the given container
+    ///
+    /// All remaining items are added to the given `container` via its
+    /// [Extend] implementation, without collecting them into a temporary
+    /// container first. This allows accumulating the items of multiple
+    /// traversals in a single container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let mut items = Vec::new();
+    /// 1u8.trans_iter_with(|n| if *n < 3 { vec![n + 1] } else { Vec::new() }).collect_into(&mut items);
+    /// 5u8.trans_iter_with(|n| if *n < 6 { vec![n + 1] } else { Vec::new() }).collect_into(&mut items);
+    /// assert_eq!(items, vec![1, 2, 3, 5, 6]);
+    /// ```
+    pub fn collect_into<C: Extend<T>>(self, container: &mut C) -> &mut C {
+        container.extend(self);
+        container
+    }
+
+    /// Filter the items yielded by the recursion function
+    ///
+    /// The returned iterator will only enqueue items yielded by the recursion
+    /// function for which the given `predicate` returns `true`. The
+    /// `predicate` is called with the item passed to the recursion function,
+    /// i.e. the "parent", as the first and the candidate "child" as the second
+    /// argument. Thus, unlike [Iterator::filter], the `predicate` filters
+    /// edges rather than items: an item rejected via one edge may still be
+    /// reached via another. Items rejected are never passed to the recursion
+    /// function. Items already enqueued, including the initial items, are not
+    /// affected.
+    ///
+    /// Note that the items yielded by the recursion function are collected
+    /// into a [Vec] for each call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let items: Vec<_> = 1u8
+    ///     .trans_iter_with(|n| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+    ///     .filter_children(|p, c| *p != 2 || *c != 4)
+    ///     .collect();
+    /// assert_eq!(items, vec![1, 2, 3, 5, 6, 7]);
+    /// ```
+    pub fn filter_children<P>(self, mut predicate: P) -> TransIter<impl FnMut(&T) -> Vec<T>, Vec<T>, T>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        self.map_recursion(|mut get_next| move |parent: &T| {
+            get_next(parent).into_iter().filter(|child| predicate(parent, child)).collect()
+        })
+    }
+
+    /// Observe consecutive siblings
+    ///
+    /// The returned iterator yields the same items in the same order as this
+    /// iterator would. However, for each call to the recursion function, the
+    /// given `observer` is called for each pair of consecutive items yielded
+    /// by the recursion function, i.e. `(child_0, child_1)`,
+    /// `(child_1, child_2)` and so on, before they are enqueued.
+    ///
+    /// Note that the items yielded by the recursion function are collected
+    /// into a [Vec] for each call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let mut pairs = Vec::new();
+    /// let items: Vec<_> = 1u8
+    ///     .trans_iter_with(|n| if *n == 1 { vec![2, 3, 4] } else { Vec::new() })
+    ///     .with_sibling_pairs(|a, b| pairs.push((*a, *b)))
+    ///     .collect();
+    /// assert_eq!(items, vec![1, 2, 3, 4]);
+    /// assert_eq!(pairs, vec![(2, 3), (3, 4)]);
+    /// ```
+    pub fn with_sibling_pairs<O>(self, mut observer: O) -> TransIter<impl FnMut(&T) -> Vec<T>, Vec<T>, T>
+    where O: FnMut(&T, &T),
+    {
+        self.map_recursion(|mut get_next| move |item: &T| {
+            let items: Vec<_> = get_next(item).into_iter().collect();
+            items.windows(2).for_each(|w| observer(&w[0], &w[1]));
+            items
+        })
+    }
+
+    /// Stop recursing at items matching a predicate
+    ///
+    /// The returned iterator will still yield items for which the given
+    /// `predicate` returns `true`, but it will not pass them to the recursion
+    /// function. Thus, it will not yield items which are only reachable through
+    /// such items. Other items, including siblings of the matching items, are
+    /// not affected. The `predicate` is called once for each item, right
+    /// before it is yielded.
+    ///
+    /// For not yielding the matching items either, i.e. for pruning them
+    /// together with the items reachable through them, consider
+    /// [filter_children](TransIter::filter_children). Note, however, that it
+    /// differs from this function in two ways: its predicate is called with
+    /// both the item passed to the recursion function and the item yielded by
+    /// it, and it only affects items yielded by the recursion function. Items
+    /// in the initial set or otherwise already enqueued are yielded regardless.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let items: Vec<_> = 1u8
+    ///     .trans_iter_with(|n| if *n < 8 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+    ///     .stop_at(|n| *n == 2)
+    ///     .collect();
+    /// assert_eq!(items, vec![1, 2, 3, 6, 7, 12, 13, 14, 15]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn stop_at<P>(self, mut predicate: P) -> TransIter<
+        impl FnMut(&T) -> std::iter::Flatten<std::option::IntoIter<I>>,
+        std::iter::Flatten<std::option::IntoIter<I>>,
+        T,
+    > where P: FnMut(&T) -> bool {
+        self.map_recursion(|mut get_next| move |item: &T| {
+            if predicate(item) { None } else { Some(get_next(item)) }.into_iter().flatten()
+        })
+    }
+
+    /// Drop items equal to the item passed to the recursion function
+    ///
+    /// The returned iterator will not enqueue items yielded by the recursion
+    /// function which are equal to the item passed to it, i.e. "children"
+    /// equal to their "parent". This guards against the most common source of
+    /// infinite iteration, a node listing itself as its own child, without the
+    /// need to keep track of visited items.
+    ///
+    /// Note that longer cycles, e.g. a node listing its "grandparent" as a
+    /// child, are not detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let items: Vec<_> = 1u8
+    ///     .trans_iter_with(|n| if *n < 4 { vec![*n, n + 1] } else { vec![*n] })
+    ///     .guard_self_loops()
+    ///     .collect();
+    /// assert_eq!(items, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn guard_self_loops(self) -> TransIter<impl FnMut(&T) -> Vec<T>, Vec<T>, T> where T: PartialEq {
+        self.filter_children(|parent, child| parent != child)
     }
 
     /// Convert this iterator into a [TransPrioQueue]
     ///
     /// The [TransPrioQueue] will yield the same items the [TransIter] would.
+    /// Its initial set will consist of the items currently enqueued.
+    ///
+    /// Settings affecting which items are reachable, such as those applied via
+    /// [filter_children](TransIter::filter_children) or
+    /// [guard_self_loops](TransIter::guard_self_loops), are part of the
+    /// recursion function and are thus preserved. The mode, e.g. set via
+    /// [depth_first](TransIter::depth_first), only affects the order in which
+    /// items are yielded and does not apply to a [TransPrioQueue]. It is
+    /// dropped.
     pub fn into_trans_prio_queue(self) -> TransPrioQueue<F, I, T> where T: Ord {
         TransPrioQueue::new_multi(self.queue, self.get_next)
     }
+
+    /// Convert this iterator into a [BestFirst] iterator
+    ///
+    /// The [BestFirst] iterator will yield the same items the [TransIter]
+    /// would, in an order guided by the given `heuristic`. Like
+    /// [into_trans_prio_queue](TransIter::into_trans_prio_queue), this function
+    /// preserves settings affecting which items are reachable and drops the
+    /// mode.
+    pub fn into_best_first<G: FnMut(&T) -> K, K: Ord>(self, heuristic: G) -> BestFirst<F, I, T, G, K> {
+        BestFirst::new_multi(self.queue, self.get_next, heuristic)
+    }
+
+    /// Convert this iterator into an iterator over traversal [Event]s
+    ///
+    /// The returned [Events] iterator traverses the items depth first,
+    /// preserving the order of siblings, regardless of the mode this iterator
+    /// was configured with. For each item, it yields an [Event::Enter] before
+    /// and an [Event::Leave] after the events of all the items reachable from
+    /// that item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::{Event, IntoTransIter};
+    ///
+    /// let events: Vec<_> = 1u8
+    ///     .trans_iter_with(|n| if *n < 3 { vec![n + 1] } else { Vec::new() })
+    ///     .events()
+    ///     .collect();
+    /// assert_eq!(
+    ///     events,
+    ///     vec![Event::Enter(1), Event::Enter(2), Event::Enter(3), Event::Leave(3), Event::Leave(2), Event::Leave(1)],
+    /// );
+    /// ```
+    pub fn events(self) -> Events<F, I, T> where T: Clone {
+        self.into()
+    }
+
+    /// Convert this iterator into an iterator yielding subtree sizes
+    ///
+    /// The returned [SubtreeSizes] iterator traverses the items depth first,
+    /// preserving the order of siblings, regardless of the mode this iterator
+    /// was configured with. Items are yielded in post-order, since the size of
+    /// an item's subtree is only known once all items reachable from it were
+    /// visited. Each item is paired with the number of items in its subtree,
+    /// including the item itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let sizes: Vec<_> = 1u8
+    ///     .trans_iter_with(|n| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+    ///     .with_subtree_size()
+    ///     .collect();
+    /// assert_eq!(sizes, vec![(1, 4), (1, 5), (3, 2), (1, 6), (1, 7), (3, 3), (7, 1)]);
+    /// ```
+    pub fn with_subtree_size(self) -> SubtreeSizes<F, I, T> {
+        self.into()
+    }
+
+    /// Make this iterator iterate breadth first, yielding items with their depth
+    ///
+    /// The returned iterator yields the same items in the same order as this
+    /// iterator would after calling [breadth_first](TransIter::breadth_first),
+    /// but paired with their depth. Items in the current initial set or
+    /// frontier have a depth of `0`, items yielded by the recursion function
+    /// have the depth of the item passed to it plus one.
+    ///
+    /// The depths yielded are guaranteed to be non-decreasing, which allows
+    /// detecting transitions from one level to the next without buffering an
+    /// entire level. Note that this guarantee is voided if the returned
+    /// iterator is switched to another mode.
+    ///
+    /// Depths saturate at [usize::MAX], i.e. all items at that depth or deeper
+    /// are yielded with a depth of [usize::MAX].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let names: Vec<_> = String::new()
+    ///     .trans_iter_with(|s| { let s = s.clone(); ["a", "b"].iter().map(move |c| s.clone() + c)})
+    ///     .depth_boundaries()
+    ///     .take(7)
+    ///     .collect();
+    /// assert_eq!(names, vec![
+    ///     (0, "".to_owned()),
+    ///     (1, "a".to_owned()), (1, "b".to_owned()),
+    ///     (2, "aa".to_owned()), (2, "ab".to_owned()), (2, "ba".to_owned()), (2, "bb".to_owned()),
+    /// ]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn depth_boundaries(self) -> TransIter<
+        impl FnMut(&(usize, T)) -> std::iter::Zip<std::iter::Repeat<usize>, I::IntoIter>,
+        std::iter::Zip<std::iter::Repeat<usize>, I::IntoIter>,
+        (usize, T),
+    > {
+        self.breadth_first().with_depth_from(0)
+    }
+
+    /// Pair items with their index among their siblings
+    ///
+    /// The returned iterator yields the same items in the same order as this
+    /// iterator would, but paired with their position in the sequence of items
+    /// yielded by the call to the recursion function which produced them.
+    /// Items in the current frontier, e.g. the initial set, are paired with
+    /// their position in that frontier. The mode is preserved, but note that
+    /// the indices always reflect the order in which the recursion function
+    /// yielded the items, even if the mode does not preserve it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let items: Vec<_> = String::new()
+    ///     .trans_iter_with(|s| { let s = s.clone(); ["a", "b"].iter().map(move |c| s.clone() + c)})
+    ///     .depth_first()
+    ///     .with_child_index()
+    ///     .take(5)
+    ///     .collect();
+    /// assert_eq!(items, vec![
+    ///     (0, "".to_owned()), (0, "a".to_owned()), (0, "aa".to_owned()), (0, "aaa".to_owned()), (0, "aaaa".to_owned()),
+    /// ]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn with_child_index(self) -> TransIter<
+        impl FnMut(&(usize, T)) -> std::iter::Enumerate<I::IntoIter>,
+        std::iter::Enumerate<I::IntoIter>,
+        (usize, T),
+    > {
+        let mut get_next = self.get_next;
+        TransIter {
+            get_next: move |(_, item): &(usize, T)| get_next(item).into_iter().enumerate(),
+            queue: self.queue.into_iter().enumerate().collect(),
+            mode: self.mode,
+        }
+    }
+
+    /// Pair items with their depth, starting at the given depth
+    ///
+    /// Items in the current frontier are assigned the given `depth`, items
+    /// yielded by the recursion function the depth of the item passed to it
+    /// plus one, saturating at [usize::MAX]. The mode is preserved.
+    #[allow(clippy::type_complexity)]
+    fn with_depth_from(self, depth: usize) -> TransIter<
+        impl FnMut(&(usize, T)) -> std::iter::Zip<std::iter::Repeat<usize>, I::IntoIter>,
+        std::iter::Zip<std::iter::Repeat<usize>, I::IntoIter>,
+        (usize, T),
+    > {
+        let mut get_next = self.get_next;
+        TransIter {
+            get_next: move |(depth, item): &(usize, T)| std::iter::repeat(depth.saturating_add(1)).zip(get_next(item)),
+            queue: self.queue.into_iter().map(|item| (depth, item)).collect(),
+            mode: self.mode,
+        }
+    }
+
+    /// Enqueue items according to the current mode
+    fn enqueue(&mut self, items: impl IntoIterator<Item = T>) {
+        self.mode.enqueue(&mut self.queue, items)
+    }
+
+    /// Create an iterator with the same state but another recursion function
+    ///
+    /// The recursion function of the iterator returned is derived from this
+    /// iterator's recursion function via the given function.
+    fn map_recursion<G, J>(self, map: impl FnOnce(F) -> G) -> TransIter<G, J, T>
+    where G: FnMut(&T) -> J,
+          J: IntoIterator<Item = T>,
+    {
+        TransIter {get_next: map(self.get_next), queue: self.queue, mode: self.mode}
+    }
+}
+
+impl<F, I, T> Clone for TransIter<F, I, T>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {get_next: self.get_next.clone(), queue: self.queue.clone(), mode: self.mode}
+    }
 }
 
 impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> Iterator for TransIter<F, I, T> {
@@ -124,40 +692,60 @@ impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> Iterator for TransIter<F,
 
     fn next(&mut self) -> Option<T> {
         let res = self.queue.pop_front();
-        res.as_ref().map(&mut self.get_next).map(|items| match self.mode {
-            Mode::BreadthFirst          => self.queue.extend(items),
-            Mode::DepthFirst            => {
-                let mut items = Vec::from_iter(items);
-                self.queue.reserve(items.len());
-                while let Some(i) = items.pop() {
-                    self.queue.push_front(i);
-                }
-            },
-            Mode::DepthFirstUnordered   => {
-                let items = items.into_iter();
-                self.queue.reserve(items.size_hint().0);
-                items.for_each(|i| self.queue.push_front(i))
-            },
-        });
+        if let Some(items) = res.as_ref().map(&mut self.get_next) {
+            self.enqueue(items)
+        }
 
         res
     }
 }
 
 
-#[derive(Copy, Clone, Debug)]
-enum Mode {
+/// Order in which a [TransIter] yields items
+///
+/// The order, or mode, of a [TransIter] may be set via
+/// [ordered](TransIter::ordered) or one of the dedicated functions such as
+/// [depth_first](TransIter::depth_first).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TraversalOrder {
+    /// Breadth first, see [TransIter::breadth_first]
     BreadthFirst,
+    /// Depth first, see [TransIter::depth_first]
     DepthFirst,
+    /// Depth first without preserving sibling order, see
+    /// [TransIter::depth_first_unordered]
     DepthFirstUnordered,
 }
 
-impl Default for Mode {
+#[allow(clippy::derivable_impls)]
+impl Default for TraversalOrder {
     fn default() -> Self {
         Self::BreadthFirst
     }
 }
 
+impl TraversalOrder {
+    /// Enqueue items in the given queue according to this mode
+    pub(crate) fn enqueue<T>(self, queue: &mut std::collections::VecDeque<T>, items: impl IntoIterator<Item = T>) {
+        match self {
+            Self::BreadthFirst          => queue.extend(items),
+            Self::DepthFirst            => {
+                let mut items = Vec::from_iter(items);
+                queue.reserve(items.len());
+                while let Some(i) = items.pop() {
+                    queue.push_front(i);
+                }
+            },
+            Self::DepthFirstUnordered   => {
+                let items = items.into_iter();
+                queue.reserve(items.size_hint().0);
+                items.for_each(|i| queue.push_front(i))
+            },
+        }
+    }
+}
+
 
 /// Transitive priority queue
 ///
@@ -169,14 +757,22 @@ impl Default for Mode {
 /// be yielded after its "parent".
 ///
 /// Of the currently enqueued items, the queue will always yield the greatest
-/// one as defined via the item type's implementation of [Ord].
+/// one as defined via the item type's implementation of [Ord]. Of multiple
+/// items which compare equal, the queue may yield any one first. For breaking
+/// ties by insertion order, use a [StablePrioQueue] instead.
+///
+/// Note that the last item yielded is not necessarily the least reachable item,
+/// since items yielded by the recursion function may be greater than the item
+/// passed to it. Retrieving the last item via [Iterator::last] involves popping
+/// every single item from the heap. For retrieving the least reachable item,
+/// consider using [min_reachable](TransPrioQueue::min_reachable) instead.
 ///
 /// Note that the iterator itself will not filter items which are reachable via
 /// multiple paths. Generally, this iterator is not suitable for navigating
 /// potentially cyclic structures on its own. For such structures, consider
 /// implementing the necessary filtering in the recursion function supplied
 /// during iterator creation.
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct TransPrioQueue<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Ord> {
     get_next: F,
     data: std::collections::BinaryHeap<T>,
@@ -200,6 +796,102 @@ impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Ord> TransPrioQueue<F, I,
     pub fn new_multi(initial: impl IntoIterator<Item = T>, recursion: F) -> Self {
         Self {get_next: recursion, data: FromIterator::from_iter(initial)}
     }
+
+    /// Create a new transitive priority queue from an existing heap
+    ///
+    /// The queue will yield all elements which are transitively reachable
+    /// from the items in the given `heap` through the given `recursion`
+    /// function, including the items in the `heap`. The `heap` is used as is,
+    /// i.e. no additional allocation or reordering takes place.
+    pub fn from_heap(heap: std::collections::BinaryHeap<T>, recursion: F) -> Self {
+        Self {get_next: recursion, data: heap}
+    }
+
+    /// Create a new transitive priority queue from a snapshot
+    ///
+    /// The queue will yield the same items the queue from which the given
+    /// `state` was taken would have, given the same `recursion` function.
+    pub fn resume_with(state: PrioQueueState<T>, recursion: F) -> Self {
+        Self::from_heap(state.data, recursion)
+    }
+
+    /// Retrieve the current frontier
+    ///
+    /// The returned iterator yields references to the items currently
+    /// enqueued, i.e. items which were not yet yielded and not yet passed to
+    /// the recursion function, in an arbitrary order.
+    pub fn frontier(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// Check whether this queue is exhausted
+    ///
+    /// Returns `true` if no items are currently enqueued, i.e. if any further
+    /// call to [next](Iterator::next) will return `None`.
+    pub fn is_exhausted(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Take a snapshot of the current frontier
+    ///
+    /// The returned [PrioQueueState] holds copies of the items currently
+    /// enqueued. It may be used for resuming the traversal later via
+    /// [resume_with](TransPrioQueue::resume_with).
+    pub fn frontier_state(&self) -> PrioQueueState<T> where T: Clone {
+        PrioQueueState {data: self.data.clone()}
+    }
+
+    /// Drain this queue into the given container
+    ///
+    /// All remaining items are added to the given `container` via its
+    /// [Extend] implementation, without collecting them into a temporary
+    /// container first.
+    pub fn collect_into<C: Extend<T>>(self, container: &mut C) -> &mut C {
+        container.extend(self);
+        container
+    }
+
+    /// Convert this queue into a [StablePrioQueue]
+    ///
+    /// The [StablePrioQueue] will yield the same items this queue would, but
+    /// break ties between equal items by the order in which they were
+    /// enqueued. Since that order is not known for the items currently
+    /// enqueued, ties between those are broken in an arbitrary order.
+    pub fn stable(self) -> StablePrioQueue<F, I, T> {
+        StablePrioQueue::new_multi(self.data.into_sorted_vec().into_iter().rev(), self.get_next)
+    }
+
+    /// Retrieve the least of all the remaining reachable items
+    ///
+    /// This function consumes the queue, expanding all remaining items. It
+    /// returns the least item as defined via the item type's implementation of
+    /// [Ord]. Unlike [Iterator::min], this function does not maintain the heap
+    /// while doing so. Note that the result is not necessarily the item
+    /// [Iterator::last] would return, unless the recursion function never
+    /// yields items greater than the one passed to it.
+    pub fn min_reachable(self) -> Option<T> {
+        let Self {mut get_next, data} = self;
+        let mut pending = data.into_vec();
+        let mut min = None;
+        while let Some(item) = pending.pop() {
+            pending.extend(get_next(&item));
+            min = match min {
+                Some(min) if min <= item => Some(min),
+                _ => Some(item),
+            };
+        }
+        min
+    }
+}
+
+impl<F, I, T> Clone for TransPrioQueue<F, I, T>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      T: Ord + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {get_next: self.get_next.clone(), data: self.data.clone()}
+    }
 }
 
 impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Ord> Iterator for TransPrioQueue<F, I, T> {
@@ -207,7 +899,9 @@ impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Ord> Iterator for TransPri
 
     fn next(&mut self) -> Option<T> {
         let res = self.data.pop();
-        res.as_ref().map(&mut self.get_next).map(|items| self.data.extend(items));
+        if let Some(items) = res.as_ref().map(&mut self.get_next) {
+            self.data.extend(items)
+        }
         res
     }
 }
@@ -253,6 +947,84 @@ pub trait IntoTransIter<T> {
     {
         self.trans_iter_with(recursion).into_trans_prio_queue()
     }
+
+    /// Create a [TransIter] from this value with an optional recursion
+    ///
+    /// Create a [TransIter] with an initial set derived from this value and
+    /// the given recursion function. The recursion function may return `None`
+    /// for items known to be leaves, in which case no items are enqueued. This
+    /// avoids constructing an empty iterator for such items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::IntoTransIter;
+    ///
+    /// let items: Vec<_> = 1u8
+    ///     .trans_iter_opt_with(|n| if *n < 4 { Some(vec![2 * n, 2 * n + 1]) } else { None })
+    ///     .collect();
+    /// assert_eq!(items, vec![1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn trans_iter_opt_with<F, I>(self, mut recursion: F) -> TransIter<
+        impl FnMut(&T) -> std::iter::Flatten<std::option::IntoIter<I>>,
+        std::iter::Flatten<std::option::IntoIter<I>>,
+        T,
+    >
+    where Self: Sized,
+          F: FnMut(&T) -> Option<I>,
+          I: IntoIterator<Item = T>,
+    {
+        self.trans_iter_with(move |item: &T| recursion(item).into_iter().flatten())
+    }
+
+    /// Create a [Controlled] iterator from this value
+    ///
+    /// Create a [Controlled] iterator with an initial set derived from this
+    /// value and the given recursion function. The recursion function may end
+    /// the traversal by returning [Recurse::Stop].
+    fn trans_iter_with_control<F, I>(self, recursion: F) -> Controlled<F, I, T>
+    where Self: Sized,
+          F: FnMut(&T) -> Recurse<I>,
+          I: IntoIterator<Item = T>,
+    {
+        self.trans_iter_with(recursion).into()
+    }
+
+    /// Create a [Consuming] iterator from this value
+    ///
+    /// Create a [Consuming] iterator with an initial set derived from this
+    /// value and the given recursion function. The recursion function receives
+    /// items by value and returns the value to yield, if any, alongside the
+    /// items to enqueue.
+    ///
+    /// The initial set and the mode are taken from the [TransIter] created via
+    /// [trans_iter_with](IntoTransIter::trans_iter_with), the recursion function
+    /// of which is never called. Thus, if an implementation of
+    /// [trans_iter_with](IntoTransIter::trans_iter_with) selects a mode, the
+    /// returned iterator will iterate in that mode.
+    fn trans_iter_consuming_with<F, I>(self, recursion: F) -> Consuming<F, I, T>
+    where Self: Sized,
+          F: FnMut(T) -> (Option<T>, I),
+          I: IntoIterator<Item = T>,
+    {
+        // The recursion function of the intermediate TransIter is never called
+        Consuming::from_state(self.trans_iter_with(|_| std::iter::empty()).into_state(), recursion)
+    }
+
+    /// Create a [BestFirst] iterator from this value
+    ///
+    /// Create a [BestFirst] iterator with an initial set derived from this
+    /// value, the given recursion function and the given heuristic.
+    fn best_first_with<F, I, G, K>(self, recursion: F, heuristic: G) -> BestFirst<F, I, T, G, K>
+    where Self: Sized,
+          F: FnMut(&T) -> I,
+          I: IntoIterator<Item = T>,
+          G: FnMut(&T) -> K,
+          K: Ord,
+    {
+        self.trans_iter_with(recursion).into_best_first(heuristic)
+    }
 }
 
 impl<T> IntoTransIter<T> for T {
@@ -286,20 +1058,116 @@ pub trait AutoTransIter<T>: IntoTransIter<T> + Sized {
     ///
     /// Create a [TransIter] with an initial set derived from this value and the
     /// type specific recursion function.
+    #[allow(clippy::type_complexity)]
     fn trans_iter(self) -> TransIter<fn(&T) -> Self::RecIter, Self::RecIter, T> {
         self.trans_iter_with(Self::recurse)
     }
 
+    /// Create a [TransIter] iterating in the given order from this value
+    ///
+    /// Create a [TransIter] with an initial set derived from this value and the
+    /// type specific recursion function, iterating in the given `order`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use transiter::{AutoTransIter, TraversalOrder};
+    ///
+    /// struct Node {
+    ///     name: &'static str,
+    ///     children: Vec<Node>,
+    /// }
+    ///
+    /// impl<'a> AutoTransIter<&'a Node> for &'a Node {
+    ///     type RecIter = std::slice::Iter<'a, Node>;
+    ///
+    ///     fn recurse(item: &&'a Node) -> Self::RecIter {
+    ///         item.children.iter()
+    ///     }
+    /// }
+    ///
+    /// let root = Node {
+    ///     name: "root",
+    ///     children: vec![
+    ///         Node {name: "a", children: vec![Node {name: "c", children: Vec::new()}]},
+    ///         Node {name: "b", children: Vec::new()},
+    ///     ],
+    /// };
+    ///
+    /// let names: Vec<_> = (&root).trans_iter_ordered(TraversalOrder::DepthFirst).map(|n| n.name).collect();
+    /// assert_eq!(names, vec!["root", "a", "c", "b"]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn trans_iter_ordered(self, order: TraversalOrder) -> TransIter<fn(&T) -> Self::RecIter, Self::RecIter, T> {
+        self.trans_iter().ordered(order)
+    }
+
     /// Create a [TransPrioQueue] from this value
     ///
     /// Create a [TransPrioQueue] with an initial set derived from this value
     /// and the type specific recursion function.
+    #[allow(clippy::type_complexity)]
     fn trans_prio_queue(self) -> TransPrioQueue<fn(&T) -> Self::RecIter, Self::RecIter, T> where T: Ord {
         self.trans_prio_queue_with(Self::recurse)
     }
 }
 
 
+/// Create a [TransIter] over references from a reference to some value
+///
+/// This trait defines the [trans_iter_ref](AutoTransIterRef::trans_iter_ref)
+/// function which, when called on a value of a type `N` for which `&N`
+/// implements [AutoTransIter], returns a [TransIter] over references to `N`.
+/// This library provides a blanket implementation for all types.
+///
+/// # Example
+///
+/// ```
+/// use transiter::{AutoTransIter, AutoTransIterRef};
+///
+/// struct Node {
+///     name: &'static str,
+///     children: Vec<Node>,
+/// }
+///
+/// impl<'a> AutoTransIter<&'a Node> for &'a Node {
+///     type RecIter = std::slice::Iter<'a, Node>;
+///
+///     fn recurse(item: &&'a Node) -> Self::RecIter {
+///         item.children.iter()
+///     }
+/// }
+///
+/// let root = Node {
+///     name: "root",
+///     children: vec![
+///         Node {name: "a", children: vec![Node {name: "c", children: Vec::new()}]},
+///         Node {name: "b", children: Vec::new()},
+///     ],
+/// };
+///
+/// let names: Vec<_> = root.trans_iter_ref().map(|n| n.name).collect();
+/// assert_eq!(names, vec!["root", "a", "b", "c"]);
+/// ```
+pub trait AutoTransIterRef {
+    /// Create a [TransIter] over references from a reference to this value
+    ///
+    /// Create a [TransIter] with a reference to this value as the initial
+    /// item and the recursion function defined by the [AutoTransIter]
+    /// implementation for references.
+    #[allow(clippy::type_complexity)]
+    fn trans_iter_ref<'a>(&'a self) -> TransIter<
+        fn(&&'a Self) -> <&'a Self as AutoTransIter<&'a Self>>::RecIter,
+        <&'a Self as AutoTransIter<&'a Self>>::RecIter,
+        &'a Self,
+    > where &'a Self: AutoTransIter<&'a Self> {
+        self.trans_iter()
+    }
+}
+
+impl<N: ?Sized> AutoTransIterRef for N {}
+
+
 #[cfg(test)]
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
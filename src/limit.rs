@@ -0,0 +1,96 @@
+//! Resumable limitation of the number of items yielded
+
+use super::TransIter;
+
+
+/// Transitive iterator yielding a limited number of items
+///
+/// This iterator is created via [TransIter::limit]. It yields at most a given
+/// number of items. Unlike [Iterator::take], it allows inspecting and
+/// retrieving the underlying [TransIter] afterwards, e.g. for persisting its
+/// frontier and resuming the traversal later.
+///
+/// # Example
+///
+/// ```
+/// use transiter::{IntoTransIter, TransIter};
+///
+/// let recursion = |n: &u8| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() };
+///
+/// let mut page = 1u8.trans_iter_with(recursion).limit(3);
+/// assert_eq!(page.by_ref().collect::<Vec<_>>(), vec![1, 2, 3]);
+///
+/// let frontier: Vec<_> = page.remaining_frontier_after_limit().cloned().collect();
+/// assert_eq!(frontier, vec![4, 5, 6, 7]);
+///
+/// let rest: Vec<_> = TransIter::new_multi(frontier, recursion).collect();
+/// assert_eq!(rest, vec![4, 5, 6, 7]);
+/// ```
+#[derive(Debug)]
+pub struct Limit<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> {
+    inner: TransIter<F, I, T>,
+    remaining: usize,
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> Limit<F, I, T> {
+    /// Create a new limited iterator yielding at most `limit` items
+    pub(crate) fn new(inner: TransIter<F, I, T>, limit: usize) -> Self {
+        Self {inner, remaining: limit}
+    }
+
+    /// Retrieve the number of items this iterator may still yield
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Retrieve the frontier of the underlying [TransIter]
+    ///
+    /// Once the limit is reached, the returned iterator yields the items which
+    /// would be yielded next, in the order the underlying [TransIter] would
+    /// yield them if the recursion function did not yield any items. A
+    /// [TransIter] created with these items as its initial set, the same
+    /// recursion function and the same mode will yield the remaining items in
+    /// the same order the underlying [TransIter] would.
+    pub fn remaining_frontier_after_limit(&self) -> impl Iterator<Item = &T> {
+        self.inner.frontier()
+    }
+
+    /// Retrieve the underlying [TransIter]
+    ///
+    /// The [TransIter] returned will continue with the items not yielded by
+    /// this iterator.
+    pub fn into_inner(self) -> TransIter<F, I, T> {
+        self.inner
+    }
+}
+
+impl<F, I, T> Clone for Limit<F, I, T>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {inner: self.inner.clone(), remaining: self.remaining}
+    }
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> Iterator for Limit<F, I, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None
+        }
+
+        let res = self.inner.next();
+        if res.is_some() {
+            self.remaining -= 1
+        }
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = if self.remaining > 0 && !self.inner.is_exhausted() { 1 } else { 0 };
+        (lower, Some(self.remaining))
+    }
+}
@@ -0,0 +1,153 @@
+//! Priority queue with deterministic tie-breaking
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+
+/// Transitive priority queue breaking ties by insertion order
+///
+/// This iterator behaves like a [TransPrioQueue](crate::TransPrioQueue), except
+/// that of multiple enqueued items which compare equal, it will always yield
+/// the one which was enqueued first. Thus, the order in which items are
+/// yielded is fully determined by the recursion function and the items' [Ord]
+/// implementation.
+///
+/// In order to track the order of insertion, the queue associates a `u64`
+/// sequence number with each item enqueued.
+///
+/// # Example
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// /// String ordered only by its length
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct ByLen(String);
+///
+/// impl Ord for ByLen {
+///     fn cmp(&self, other: &Self) -> Ordering {
+///         self.0.len().cmp(&other.0.len())
+///     }
+/// }
+///
+/// impl PartialOrd for ByLen {
+///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+///         Some(self.cmp(other))
+///     }
+/// }
+///
+/// let names: Vec<_> = transiter::StablePrioQueue::new(
+///     ByLen(String::new()),
+///     |ByLen(s)| if s.len() < 2 { vec![ByLen(s.clone() + "a"), ByLen(s.clone() + "b")] } else { Vec::new() },
+/// ).map(|ByLen(s)| s).collect();
+/// assert_eq!(names, vec!["", "a", "aa", "ab", "b", "ba", "bb"]);
+/// ```
+#[derive(Debug)]
+pub struct StablePrioQueue<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Ord> {
+    get_next: F,
+    data: BinaryHeap<Sequenced<T>>,
+    next_seq: u64,
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Ord> StablePrioQueue<F, I, T> {
+    /// Create a new stable transitive priority queue
+    ///
+    /// The queue will yield all elements which are transitively reachable
+    /// from the `initial` item through the given `recursion` function,
+    /// including the `initial` itself.
+    pub fn new(initial: T, recursion: F) -> Self {
+        Self::new_multi(std::iter::once(initial), recursion)
+    }
+
+    /// Create a new stable transitive priority queue with multiple initial items
+    ///
+    /// The queue will yield all elements which are transitively reachable
+    /// from the `initial` set of items through the given `recursion` function,
+    /// including the items in the initial set. Ties between items in the
+    /// initial set are broken according to their order in that set.
+    pub fn new_multi(initial: impl IntoIterator<Item = T>, recursion: F) -> Self {
+        let mut res = Self {get_next: recursion, data: Default::default(), next_seq: 0};
+        res.enqueue(initial);
+        res
+    }
+
+    /// Retrieve the current frontier
+    ///
+    /// The returned iterator yields references to the items currently
+    /// enqueued, i.e. items which were not yet yielded and not yet passed to
+    /// the recursion function, in an arbitrary order.
+    pub fn frontier(&self) -> impl Iterator<Item = &T> {
+        self.data.iter().map(|s| &s.item)
+    }
+
+    /// Check whether this queue is exhausted
+    ///
+    /// Returns `true` if no items are currently enqueued, i.e. if any further
+    /// call to [next](Iterator::next) will return `None`.
+    pub fn is_exhausted(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Enqueue items, assigning sequence numbers in the order given
+    fn enqueue(&mut self, items: impl IntoIterator<Item = T>) {
+        let next_seq = &mut self.next_seq;
+        self.data.extend(items.into_iter().map(|item| {
+            let seq = *next_seq;
+            *next_seq += 1;
+            Sequenced {item, seq: Reverse(seq)}
+        }))
+    }
+}
+
+impl<F, I, T> Clone for StablePrioQueue<F, I, T>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      T: Ord + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {get_next: self.get_next.clone(), data: self.data.clone(), next_seq: self.next_seq}
+    }
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T: Ord> Iterator for StablePrioQueue<F, I, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let res = self.data.pop().map(|s| s.item);
+        if let Some(items) = res.as_ref().map(&mut self.get_next) {
+            self.enqueue(items)
+        }
+        res
+    }
+}
+
+
+/// Item associated with a sequence number
+///
+/// Items are ordered by the item first and the reverse sequence number second,
+/// i.e. of two equal items the one with the lower sequence number is greater.
+#[derive(Clone, Debug)]
+struct Sequenced<T: Ord> {
+    item: T,
+    seq: Reverse<u64>,
+}
+
+impl<T: Ord> Ord for Sequenced<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.item.cmp(&other.item).then_with(|| self.seq.cmp(&other.seq))
+    }
+}
+
+impl<T: Ord> PartialOrd for Sequenced<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Eq for Sequenced<T> {}
+
+impl<T: Ord> PartialEq for Sequenced<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
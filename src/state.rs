@@ -0,0 +1,44 @@
+//! Snapshots of the state of traversals
+
+use std::collections::{BinaryHeap, VecDeque};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::TraversalOrder;
+
+
+/// Snapshot of the frontier of a [TransIter](crate::TransIter)
+///
+/// A snapshot holds the items currently enqueued as well as the mode. It is
+/// created via [TransIter::frontier_state](crate::TransIter::frontier_state)
+/// and may be used for creating a new [TransIter](crate::TransIter) via
+/// [TransIter::resume_with](crate::TransIter::resume_with), which will yield
+/// the same items the original one would have.
+///
+/// With the `serde` feature enabled, snapshots implement `Serialize` and
+/// `Deserialize`, allowing a traversal to be persisted and resumed later. Since
+/// the recursion function can not be serialized, it needs to be supplied again
+/// when resuming.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FrontierState<T> {
+    pub(crate) queue: VecDeque<T>,
+    pub(crate) mode: TraversalOrder,
+}
+
+
+/// Snapshot of the frontier of a [TransPrioQueue](crate::TransPrioQueue)
+///
+/// A snapshot holds the items currently enqueued. It is created via
+/// [TransPrioQueue::frontier_state](crate::TransPrioQueue::frontier_state)
+/// and may be used for creating a new [TransPrioQueue](crate::TransPrioQueue)
+/// via [TransPrioQueue::resume_with](crate::TransPrioQueue::resume_with).
+///
+/// With the `serde` feature enabled, snapshots implement `Serialize` and
+/// `Deserialize`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PrioQueueState<T: Ord> {
+    pub(crate) data: BinaryHeap<T>,
+}
@@ -0,0 +1,65 @@
+//! Post-order traversal with subtree sizes
+
+use std::collections::VecDeque;
+
+use super::TransIter;
+
+
+/// Iterator yielding items along with the sizes of their subtrees
+///
+/// This iterator is created via [TransIter::with_subtree_size]. It traverses
+/// the items depth first, preserving the order of siblings, and yields each
+/// item in post-order, i.e. after all items reachable from it. Each item is
+/// paired with the number of items in its subtree, including the item itself.
+///
+/// The recursion function is called for an item when the traversal descends
+/// into it. Only the iterators returned by the recursion function for the
+/// items currently descended into are retained, i.e. children are not
+/// collected eagerly.
+#[derive(Debug)]
+pub struct SubtreeSizes<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> {
+    get_next: F,
+    roots: VecDeque<T>,
+    stack: Vec<(T, I::IntoIter, usize)>,
+}
+
+impl<F, I, T> Clone for SubtreeSizes<F, I, T>
+where F: FnMut(&T) -> I + Clone,
+      I: IntoIterator<Item = T>,
+      I::IntoIter: Clone,
+      T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {get_next: self.get_next.clone(), roots: self.roots.clone(), stack: self.stack.clone()}
+    }
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> From<TransIter<F, I, T>> for SubtreeSizes<F, I, T> {
+    fn from(iter: TransIter<F, I, T>) -> Self {
+        Self {get_next: iter.get_next, roots: iter.queue, stack: Default::default()}
+    }
+}
+
+impl<F: FnMut(&T) -> I, I: IntoIterator<Item = T>, T> Iterator for SubtreeSizes<F, I, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = match self.stack.last_mut() {
+                Some((_, children, _))  => children.next(),
+                None                    => self.roots.pop_front(),
+            };
+
+            if let Some(item) = next {
+                let children = (self.get_next)(&item).into_iter();
+                self.stack.push((item, children, 1));
+            } else {
+                let (item, _, size) = self.stack.pop()?;
+                if let Some((_, _, parent_size)) = self.stack.last_mut() {
+                    *parent_size = parent_size.saturating_add(size)
+                }
+                return Some((size, item))
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Utilities for testing recursion functions
+//!
+//! This module provides a recursive structure for which
+//! [quickcheck::Arbitrary] is implemented, allowing downstream
+//! crates to property-test code built on top of this library. It is only
+//! available with the `quickcheck` feature enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use transiter::AutoTransIter;
+//! use transiter::testing::Node;
+//!
+//! fn node_count(node: Node) -> bool {
+//!     node.trans_iter().depth_first().count() == node.count()
+//! }
+//!
+//! quickcheck::quickcheck(node_count as fn(Node) -> bool);
+//! ```
+
+use quickcheck::{Arbitrary, Gen};
+
+use super::AutoTransIter;
+
+
+/// Dumb recursive structure for testing
+///
+/// A `Node` has an arbitrary `id` and an arbitrary number of `children`.
+/// [AutoTransIter] is implemented for references to `Node`s, with the
+/// recursion function yielding references to the children.
+///
+/// Generated trees are limited in size: the size of the [Gen] used for
+/// generating children is half the size of the one used for their parent.
+/// Shrinking a `Node` yields its children.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Node {
+    /// Arbitrary identifier of this node
+    pub id: u128,
+    /// Children of this node
+    pub children: Vec<Self>,
+}
+
+impl Node {
+    /// Retrieve the number of nodes
+    pub fn count(&self) -> usize {
+        self.children.iter().map(Self::count).sum::<usize>() + 1
+    }
+
+    /// Retrieve the number of nodes with a given depth
+    ///
+    /// The depth is `0`-based. When called with a `depth` of `0`, this function
+    /// will therefore always yield `1` for any given `Node`.
+    pub fn count_at_depth(&self, depth: usize) -> usize {
+        if let Some(depth) = depth.checked_sub(1) {
+            self.children.iter().map(|n| n.count_at_depth(depth)).sum::<usize>()
+        } else {
+            1
+        }
+    }
+}
+
+impl<'a> AutoTransIter<&'a Node> for &'a Node {
+    type RecIter = std::slice::Iter<'a, Node>;
+
+    fn recurse(item: &&'a Node) -> Self::RecIter {
+        item.children.iter()
+    }
+}
+
+impl Arbitrary for Node {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let children = if g.size() > 0 {
+            Arbitrary::arbitrary(&mut Gen::new(g.size() / 2))
+        } else {
+            Default::default()
+        };
+        Self {id: Arbitrary::arbitrary(g), children}
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.children.clone().into_iter())
+    }
+}
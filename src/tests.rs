@@ -1,8 +1,7 @@
 //! Tests
 
-use quickcheck::{Arbitrary, Gen};
-
 use super::*;
+use testing::Node;
 
 
 #[quickcheck]
@@ -28,6 +27,12 @@ fn node_count_depth_first_unordered(node: Node) -> bool {
     node.trans_iter().depth_first_unordered().count() == count
 }
 
+#[quickcheck]
+fn node_count_depth_first_double_ended(node: Node) -> bool {
+    let count = node.count();
+    node.trans_iter().depth_first_double_ended().count() == count
+}
+
 #[quickcheck]
 fn node_count_prio_queue(node: Node) -> bool {
     let count = node.count();
@@ -84,6 +89,11 @@ fn node_order_depth_first(node: Node) -> bool {
     match_ids(ids.as_ref(), &node) == Some(&[])
 }
 
+#[quickcheck]
+fn node_order_depth_first_double_ended(node: Node) -> bool {
+    node.trans_iter().depth_first_double_ended().eq(node.trans_iter().depth_first())
+}
+
 #[quickcheck]
 fn node_order_depth_first_unordered(node: Node) -> bool {
     /// Match the subtree with the given root node, return the remaining ids
@@ -99,53 +109,734 @@ fn node_order_depth_first_unordered(node: Node) -> bool {
     match_ids(ids.as_ref(), &node) == Some(&[])
 }
 
+#[quickcheck]
+fn ordered(node: Node) -> bool {
+    node.trans_iter_ordered(TraversalOrder::BreadthFirst).eq(node.trans_iter().breadth_first()) &&
+        node.trans_iter_ordered(TraversalOrder::DepthFirst).eq(node.trans_iter().depth_first()) &&
+        node.trans_iter_ordered(TraversalOrder::DepthFirstUnordered).eq(node.trans_iter().depth_first_unordered())
+}
+
+#[test]
+fn frontier() {
+    let calls = std::cell::Cell::new(0);
+    let mut iter = 1u8.trans_iter_with(|n| {
+        calls.set(calls.get() + 1);
+        if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() }
+    });
+    assert_eq!(iter.frontier().collect::<Vec<_>>(), vec![&1]);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.frontier().collect::<Vec<_>>(), vec![&2, &3]);
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.frontier().collect::<Vec<_>>(), vec![&3, &4, &5]);
+
+    let mut iter = iter.depth_first();
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.frontier().collect::<Vec<_>>(), vec![&6, &7, &4, &5]);
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn frontier_prio_queue() {
+    let mut queue = 1u8.trans_prio_queue_with(|n| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() });
+    assert_eq!(queue.next(), Some(1));
+    assert_eq!(queue.next(), Some(3));
 
-/// Dumb recursive structure for testing
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct Node {
-    id: u128,
-    children: Vec<Self>,
+    let mut frontier: Vec<_> = queue.frontier().cloned().collect();
+    frontier.sort_unstable();
+    assert_eq!(frontier, vec![2, 6, 7]);
+    assert_eq!(queue.count(), 5);
 }
 
-impl Node {
-    /// Retrieve the number of nodes
-    pub fn count(&self) -> usize {
-        self.children.iter().map(Self::count).sum::<usize>() + 1
+#[quickcheck]
+fn is_exhausted(node: Node) -> bool {
+    let mut iter = node.trans_iter();
+    let mut queue = node.trans_prio_queue();
+    (0..node.count()).all(|_| !iter.is_exhausted() && iter.next().is_some()) && iter.is_exhausted() &&
+        (0..node.count()).all(|_| !queue.is_exhausted() && queue.next().is_some()) && queue.is_exhausted()
+}
+
+#[test]
+fn is_exhausted_empty() {
+    assert!(TransIter::new_multi(Vec::<u8>::new(), |_| Vec::new()).is_exhausted());
+    assert!(TransPrioQueue::new_multi(Vec::<u8>::new(), |_| Vec::new()).is_exhausted());
+}
+
+#[quickcheck]
+fn limit_resume(node: Node, limit: u8, order: u8) -> bool {
+    let order = [
+        TraversalOrder::BreadthFirst,
+        TraversalOrder::DepthFirst,
+        TraversalOrder::DepthFirstUnordered,
+    ][order as usize % 3];
+
+    let mut page = node.trans_iter_ordered(order).limit(limit as usize);
+    let mut ids: Vec<_> = page.by_ref().map(|n| n.id).collect();
+    if ids.len() != std::cmp::min(limit as usize, node.count()) {
+        return false
     }
 
-    /// Retrieve the number of nodes with a given depth
-    ///
-    /// The depth is `0`-based. When called with a `depth` of `0`, this function
-    /// will therefore always yield `1` for any given `Node`.
-    pub fn count_at_depth(&self, depth: usize) -> usize {
-        if let Some(depth) = depth.checked_sub(1) {
-            self.children.iter().map(|n| n.count_at_depth(depth)).sum::<usize>()
+    let frontier: Vec<_> = page.remaining_frontier_after_limit().cloned().collect();
+    ids.extend(TransIter::new_multi(frontier, |n: &&Node| n.children.iter()).ordered(order).map(|n| n.id));
+    node.trans_iter_ordered(order).map(|n| n.id).eq(ids)
+}
+
+#[quickcheck]
+fn limit_into_inner(node: Node, limit: u8) -> bool {
+    let mut page = node.trans_iter().limit(limit as usize);
+    page.by_ref().for_each(|_| ());
+    page.into_inner().eq(node.trans_iter().skip(limit as usize))
+}
+
+#[quickcheck]
+fn limit_remaining(node: Node, limit: u8) -> bool {
+    let mut page = node.trans_iter().limit(limit as usize);
+    let count = page.by_ref().count();
+    page.next().is_none() && page.remaining() == limit as usize - count
+}
+
+#[test]
+fn limit_remaining_short_page() {
+    let mut page = 1u8.trans_iter_with(|n| if *n < 2 { vec![2, 3] } else { Vec::new() }).limit(5);
+    assert_eq!(page.by_ref().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(page.remaining(), 2);
+    assert_eq!(page.next(), None);
+    assert_eq!(page.remaining(), 2);
+}
+
+#[quickcheck]
+fn frontier_state_resume(node: Node, skip: u8) -> bool {
+    let mut iter = node.trans_iter().depth_first();
+    iter.by_ref().take(skip as usize).for_each(|_| ());
+    let resumed = TransIter::resume_with(iter.frontier_state(), |n: &&Node| n.children.iter());
+    resumed.eq(iter)
+}
+
+#[quickcheck]
+fn prio_queue_frontier_state_resume(node: Node, skip: u8) -> bool {
+    let mut queue = node.trans_prio_queue();
+    queue.by_ref().take(skip as usize).for_each(|_| ());
+    let resumed = TransPrioQueue::resume_with(queue.frontier_state(), |n: &&Node| n.children.iter());
+    resumed.eq(queue)
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn frontier_state_serde() {
+    let recursion = |n: &u8| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() };
+
+    let mut iter = 1u8.trans_iter_with(recursion).depth_first();
+    assert_eq!(iter.by_ref().take(3).collect::<Vec<_>>(), vec![1, 2, 4]);
+    let json = serde_json::to_string(&iter.frontier_state()).expect("Could not serialize state");
+    let state: FrontierState<u8> = serde_json::from_str(&json).expect("Could not deserialize state");
+    assert_eq!(state, iter.frontier_state());
+    assert_eq!(TransIter::resume_with(state, recursion).collect::<Vec<_>>(), vec![5, 3, 6, 7]);
+
+    let mut queue = 1u8.trans_prio_queue_with(recursion);
+    assert_eq!(queue.by_ref().take(3).collect::<Vec<_>>(), vec![1, 3, 7]);
+    let json = serde_json::to_string(&queue.frontier_state()).expect("Could not serialize state");
+    let state: PrioQueueState<u8> = serde_json::from_str(&json).expect("Could not deserialize state");
+    assert_eq!(TransPrioQueue::resume_with(state, recursion).collect::<Vec<_>>(), vec![6, 2, 5, 4]);
+}
+
+#[quickcheck]
+fn with_cursor(node: Node) -> bool {
+    let mut iter = node.trans_iter().depth_first().with_cursor();
+    let mut last = None;
+    iter.cursor().is_none() && std::iter::from_fn(|| {
+        let item = iter.next();
+        let res = match item {
+            Some(n) => iter.cursor().map(|c| std::ptr::eq(*c, n)),
+            None => Some(iter.cursor().map(|c| c as *const _) == last),
+        };
+        last = iter.cursor().map(|c| c as *const _);
+        item.and(res)
+    }).all(|r| r)
+}
+
+#[test]
+fn with_cursor_small_tree() {
+    let mut iter = 1u8
+        .trans_iter_with(|n| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+        .depth_first()
+        .with_cursor();
+    assert_eq!(iter.cursor(), None);
+    for expected in [1, 2, 4, 5, 3, 6, 7].iter() {
+        assert_eq!(iter.next().as_ref(), Some(expected));
+        assert_eq!(iter.cursor(), Some(expected));
+    }
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.cursor(), Some(&7));
+}
+
+#[quickcheck]
+fn split_at_depth(node: Node, depth: u8) -> bool {
+    let depth = usize::from(depth & 0x7);
+    let (near, far) = node.trans_iter().split_at_depth(depth);
+    near.len() == (0..=depth).map(|d| node.count_at_depth(d)).sum::<usize>() &&
+        near.into_iter().chain(far).eq(node.trans_iter())
+}
+
+#[quickcheck]
+fn split_at_depth_depth_first(node: Node, depth: u8) -> bool {
+    let depth = usize::from(depth & 0x7);
+    let (near, far) = node.trans_iter().depth_first().split_at_depth(depth);
+    let full: Vec<_> = node.trans_iter().depth_first().with_depth_from(0).collect();
+    near.into_iter().eq(full.iter().filter(|(d, _)| *d <= depth).map(|(_, n)| *n)) &&
+        far.eq(full.iter().filter(|(d, _)| *d > depth).map(|(_, n)| *n))
+}
+
+#[quickcheck]
+fn trans_iter_opt_with(node: Node) -> bool {
+    let opt = (&node).trans_iter_opt_with(|n: &&Node| if n.children.is_empty() {
+        None
+    } else {
+        Some(n.children.iter())
+    });
+    opt.eq(node.trans_iter())
+}
+
+#[test]
+fn trans_iter_opt_with_none_and_empty() {
+    let opt: Vec<_> = 1u8
+        .trans_iter_opt_with(|n| match n {
+            1..=7   => Some(vec![2 * n, 2 * n + 1]),
+            n if n & 1 == 0 => Some(Vec::new()),
+            _       => None,
+        })
+        .depth_first()
+        .collect();
+    let plain: Vec<_> = 1u8
+        .trans_iter_with(|n| if *n < 8 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+        .depth_first()
+        .collect();
+    assert_eq!(opt, plain);
+}
+
+#[quickcheck]
+fn with_subtree_size(node: Node) -> bool {
+    let sizes: Vec<_> = node.trans_iter().with_subtree_size().collect();
+    sizes.len() == node.count() &&
+        sizes.iter().all(|(size, n)| *size == n.count()) &&
+        sizes.last().map(|(_, n)| std::ptr::eq(*n, &node)).unwrap_or(false)
+}
+
+#[test]
+fn with_subtree_size_uneven() {
+    let sizes: Vec<_> = 1u8
+        .trans_iter_with(|n| match n {
+            1 => vec![2, 3, 4],
+            2 => vec![5],
+            5 => vec![6, 7],
+            _ => Vec::new(),
+        })
+        .breadth_first()
+        .with_subtree_size()
+        .collect();
+    assert_eq!(sizes, vec![(1, 6), (1, 7), (3, 5), (4, 2), (1, 3), (1, 4), (7, 1)]);
+}
+
+#[quickcheck]
+fn trans_iter_consuming_with(node: Node) -> bool {
+    let consuming = (&node).trans_iter_consuming_with(|n: &Node| (Some(n), n.children.iter()));
+    consuming.eq(node.trans_iter())
+}
+
+#[quickcheck]
+fn trans_iter_consuming_with_depth_first(node: Node) -> bool {
+    let consuming = (&node).trans_iter_consuming_with(|n: &Node| (Some(n), n.children.iter())).depth_first();
+    consuming.eq(node.trans_iter().depth_first())
+}
+
+#[quickcheck]
+fn trans_iter_consuming_with_skip(node: Node) -> bool {
+    let consuming = (&node)
+        .trans_iter_consuming_with(|n: &Node| (Some(n).filter(|n| n.id & 1 == 0), n.children.iter()))
+        .depth_first();
+    consuming.eq(node.trans_iter().depth_first().filter(|n| n.id & 1 == 0))
+}
+
+#[test]
+fn trans_iter_consuming_with_mode() {
+    /// Initial item for which traversals are depth first
+    struct DepthFirstRoot(u8);
+
+    impl IntoTransIter<u8> for DepthFirstRoot {
+        fn trans_iter_with<F: FnMut(&u8) -> I, I: IntoIterator<Item = u8>>(
+            self,
+            recursion: F
+        ) -> TransIter<F, I, u8> {
+            TransIter::new(self.0, recursion).depth_first()
+        }
+    }
+
+    let items: Vec<_> = DepthFirstRoot(1)
+        .trans_iter_consuming_with(|n: u8| (Some(n), if n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() }))
+        .collect();
+    assert_eq!(items, vec![1, 2, 4, 5, 3, 6, 7]);
+}
+
+#[test]
+fn trans_iter_consuming_with_move() {
+    let items: Vec<_> = vec![1u8, 2, 3, 4]
+        .trans_iter_consuming_with(|mut v| if v.len() > 1 {
+            let tail = v.split_off(v.len() / 2);
+            (None, vec![v, tail])
         } else {
-            1
+            (Some(v), Vec::new())
+        })
+        .collect();
+    assert_eq!(items, vec![vec![1], vec![2], vec![3], vec![4]]);
+}
+
+#[quickcheck]
+fn reset(a: Node, b: Node) -> bool {
+    let mut iter = (&a).trans_iter().depth_first();
+    iter.next();
+    iter.reset(std::iter::once(&b));
+    iter.eq((&b).trans_iter().depth_first())
+}
+
+#[test]
+fn reset_capacity() {
+    let mut iter = 0u8.trans_iter_with(|n| if *n == 0 { (1..=64).collect() } else { Vec::new() });
+    iter.next();
+    let capacity = iter.queue.capacity();
+    assert!(capacity >= 64);
+
+    iter.reset(vec![0]);
+    assert_eq!(iter.queue.capacity(), capacity);
+    assert_eq!(iter.count(), 65);
+}
+
+#[quickcheck]
+fn collect_into(a: Node, b: Node) -> bool {
+    let mut items = Vec::new();
+    a.trans_iter().collect_into(&mut items);
+    b.trans_prio_queue().collect_into(&mut items);
+    items.into_iter().eq(a.trans_iter().chain(b.trans_prio_queue()))
+}
+
+#[quickcheck]
+fn depth_boundaries_non_decreasing(node: Node) -> bool {
+    let depths: Vec<_> = node.trans_iter().depth_first().depth_boundaries().map(|(d, _)| d).collect();
+    depths.windows(2).all(|w| w[0] <= w[1])
+}
+
+#[quickcheck]
+fn depth_boundaries_count(node: Node) -> bool {
+    let mut counts = Vec::new();
+    node.trans_iter().depth_boundaries().for_each(|(d, _)| {
+        counts.resize(std::cmp::max(counts.len(), d + 1), 0);
+        counts[d] += 1;
+    });
+    node.count_at_depth(counts.len()) == 0 &&
+        counts.into_iter().enumerate().all(|(d, c)| node.count_at_depth(d) == c)
+}
+
+#[quickcheck]
+fn depth_boundaries_order(node: Node) -> bool {
+    node.trans_iter().depth_boundaries().map(|(_, n)| n).eq(node.trans_iter().breadth_first())
+}
+
+#[test]
+fn depth_saturation() {
+    let depths: Vec<_> = 0u8
+        .trans_iter_with(|n| if *n < 3 { vec![n + 1] } else { Vec::new() })
+        .with_depth_from(usize::MAX - 1)
+        .collect();
+    assert_eq!(depths, vec![(usize::MAX - 1, 0), (usize::MAX, 1), (usize::MAX, 2), (usize::MAX, 3)]);
+}
+
+#[quickcheck]
+fn with_child_index(node: Node) -> bool {
+    /// Collect the indices of all nodes in the given subtree
+    fn collect_indices(root: &Node, indices: &mut std::collections::HashMap<*const Node, usize>) {
+        root.children.iter().enumerate().for_each(|(i, c)| {
+            indices.insert(c, i);
+            collect_indices(c, indices)
+        })
+    }
+
+    let mut indices = std::collections::HashMap::new();
+    indices.insert(&node as *const Node, 0);
+    collect_indices(&node, &mut indices);
+
+    let check = |(i, n): (usize, &Node)| indices.get(&(n as *const Node)) == Some(&i);
+    node.trans_iter().with_child_index().all(check) &&
+        node.trans_iter().depth_first().with_child_index().all(check) &&
+        node.trans_iter().depth_first_unordered().with_child_index().all(check)
+}
+
+#[test]
+fn with_child_index_paths() {
+    // Reconstruct paths from indices, exploiting that the items themselves are
+    // paths for the purpose of this test.
+    let items: Vec<_> = TransIter::new_multi(vec![vec![0], vec![1]], |p: &Vec<usize>| if p.len() < 3 {
+        vec![p.iter().cloned().chain(Some(0)).collect(), p.iter().cloned().chain(Some(1)).collect()]
+    } else {
+        Vec::new()
+    }).with_child_index().collect();
+    assert_eq!(items.len(), 14);
+    assert!(items.iter().all(|(i, p)| p.last() == Some(i)));
+}
+
+#[quickcheck]
+fn filter_children(node: Node) -> bool {
+    fn predicate(parent: &Node, child: &Node) -> bool {
+        (parent.id ^ child.id) & 3 != 0
+    }
+
+    /// Collect the ids of the given subtree in depth first order
+    fn collect_ids(root: &Node, ids: &mut Vec<u128>) {
+        ids.push(root.id);
+        root.children.iter().filter(|c| predicate(root, c)).for_each(|c| collect_ids(c, ids))
+    }
+
+    let mut ids = Vec::new();
+    collect_ids(&node, &mut ids);
+    node.trans_iter().filter_children(|p, c| predicate(p, c)).depth_first().map(|n| n.id).eq(ids)
+}
+
+#[test]
+fn filter_children_edges() {
+    // Both 3 and 4 can be reached from 1 via two paths. Only one of them is
+    // filtered.
+    let items: Vec<_> = 1u8
+        .trans_iter_with(|n| (n + 1..5).collect::<Vec<_>>())
+        .filter_children(|p, c| (*p, *c) != (1, 3) && (*p, *c) != (2, 4))
+        .collect();
+    assert_eq!(items, vec![1, 2, 4, 3, 4]);
+}
+
+#[quickcheck]
+fn with_sibling_pairs(node: Node) -> bool {
+    /// Collect the pairs of consecutive siblings in the given subtree
+    fn collect_pairs(root: &Node, pairs: &mut Vec<(u128, u128)>) {
+        pairs.extend(root.children.windows(2).map(|w| (w[0].id, w[1].id)));
+        root.children.iter().for_each(|c| collect_pairs(c, pairs))
+    }
+
+    let mut expected = Vec::new();
+    collect_pairs(&node, &mut expected);
+
+    let mut pairs = Vec::new();
+    let order_preserved = node
+        .trans_iter()
+        .depth_first()
+        .with_sibling_pairs(|a, b| pairs.push((a.id, b.id)))
+        .eq(node.trans_iter().depth_first());
+    order_preserved && pairs == expected
+}
+
+#[quickcheck]
+fn stop_at(node: Node) -> bool {
+    fn predicate(node: &Node) -> bool {
+        node.id & 3 == 0
+    }
+
+    /// Collect the ids of the given subtree in depth first order
+    fn collect_ids(root: &Node, ids: &mut Vec<u128>) {
+        ids.push(root.id);
+        if !predicate(root) {
+            root.children.iter().for_each(|c| collect_ids(c, ids))
         }
     }
+
+    let mut ids = Vec::new();
+    collect_ids(&node, &mut ids);
+    node.trans_iter().stop_at(|n| predicate(n)).depth_first().map(|n| n.id).eq(ids)
+}
+
+#[test]
+fn stop_at_sentinel() {
+    // The sentinel 2 is yielded, but not its children 4 and 5. Its sibling 3
+    // and its children 6 and 7 are not affected.
+    let items: Vec<_> = 1u8
+        .trans_iter_with(|n| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+        .stop_at(|n| *n == 2)
+        .depth_first()
+        .collect();
+    assert_eq!(items, vec![1, 2, 3, 6, 7]);
+}
+
+#[test]
+fn stop_at_skip_subtree() {
+    // Neither the sentinel 2 nor its children 4 and 5 are yielded.
+    let items: Vec<_> = 1u8
+        .trans_iter_with(|n| if *n < 4 { vec![2 * n, 2 * n + 1] } else { Vec::new() })
+        .filter_children(|_, c| *c != 2)
+        .depth_first()
+        .collect();
+    assert_eq!(items, vec![1, 3, 6, 7]);
+}
+
+#[quickcheck]
+fn guard_self_loops(node: Node) -> bool {
+    // Let every node list itself as a child in addition to its actual ones
+    let count = node.count();
+    node.trans_iter_with(|n| std::iter::once(n.clone()).chain(n.children.iter().cloned()).collect::<Vec<_>>())
+        .guard_self_loops()
+        .count() == count
+}
+
+#[test]
+fn guard_self_loops_initial() {
+    let items: Vec<_> = TransIter::new_multi(vec![1u8, 1], |n| vec![*n]).guard_self_loops().collect();
+    assert_eq!(items, vec![1, 1]);
+}
+
+#[quickcheck]
+fn controlled_no_stop(node: Node) -> bool {
+    let iter = (&node).trans_iter_with_control(|n| Recurse::Children(n.children.iter())).depth_first();
+    iter.eq(node.trans_iter().depth_first())
+}
+
+#[quickcheck]
+fn controlled_stop(node: Node, limit: u8) -> bool {
+    // Stopping at the n-th item yields exactly the first n items
+    let limit = limit as usize;
+    let mut calls = 0;
+    let ids: Vec<_> = (&node)
+        .trans_iter_with_control(|n| {
+            calls += 1;
+            if calls < limit { Recurse::Children(n.children.iter()) } else { Recurse::Stop }
+        })
+        .map(|n| n.id)
+        .collect();
+    node.trans_iter().map(|n| n.id).take(std::cmp::max(limit, 1)).eq(ids)
+}
+
+#[test]
+fn controlled_stop_discards_queue() {
+    let items: Vec<_> = Controlled::new_multi(vec![1u8, 2, 3], |n| if *n == 2 { Recurse::Stop } else { Recurse::Children(vec![10 * n]) })
+        .collect();
+    assert_eq!(items, vec![1, 2]);
 }
 
-impl<'a> AutoTransIter<&'a Node> for &'a Node {
-    type RecIter = std::slice::Iter<'a, Node>;
+#[quickcheck]
+fn interleave_count(a: Node, b: Node) -> bool {
+    let count = a.count() + b.count();
+    interleave(a.trans_iter(), b.trans_prio_queue()).count() == count
+}
+
+#[quickcheck]
+fn interleave_order(a: Vec<u8>, b: Vec<u8>) -> bool {
+    // Every other item originates from `a` as long as both have items left
+    let common = std::cmp::min(a.len(), b.len());
+    let items: Vec<_> = interleave(a.clone(), b.clone()).collect();
+    let (alternating, rest) = items.split_at(2 * common);
+    alternating.iter().step_by(2).eq(&a[..common]) &&
+        alternating.iter().skip(1).step_by(2).eq(&b[..common]) &&
+        rest.iter().eq(a[common..].iter().chain(&b[common..]))
+}
+
+#[quickcheck]
+fn event_count(node: Node) -> bool {
+    let count = node.count();
+    node.trans_iter().events().count() == 2 * count
+}
 
-    fn recurse(item: &&'a Node) -> Self::RecIter {
-        item.children.iter()
+#[quickcheck]
+fn event_order(node: Node) -> bool {
+    /// Match the subtree with the given root node, return the remaining events
+    fn match_events<'a>(events: &'a [Event<u128>], root: &Node) -> Option<&'a [Event<u128>]> {
+        events.split_first()
+            .and_then(|(first, events)| if *first == Event::Enter(root.id) { Some(events) } else { None })
+            .and_then(|events| root.children.iter().try_fold(events, |events, sub| match_events(events, sub)))
+            .and_then(|events| events.split_first())
+            .and_then(|(last, events)| if *last == Event::Leave(root.id) { Some(events) } else { None })
     }
+
+    let events: Vec<_> = node
+        .trans_iter()
+        .breadth_first()
+        .events()
+        .map(|e| match e { Event::Enter(n) => Event::Enter(n.id), Event::Leave(n) => Event::Leave(n.id) })
+        .collect();
+    match_events(events.as_ref(), &node) == Some(&[])
 }
 
-impl Arbitrary for Node {
-    fn arbitrary(g: &mut Gen) -> Self {
-        let children = if g.size() > 0 {
-            Arbitrary::arbitrary(&mut Gen::new(g.size() / 2))
-        } else {
-            Default::default()
-        };
-        Self {id: Arbitrary::arbitrary(g), children}
+#[test]
+fn event_order_multi() {
+    let events: Vec<_> = TransIter::new_multi(vec![1u8, 10], |n| if *n % 10 < 2 { vec![n + 1] } else { Vec::new() })
+        .events()
+        .collect();
+    assert_eq!(events, vec![
+        Event::Enter(1), Event::Enter(2), Event::Leave(2), Event::Leave(1),
+        Event::Enter(10), Event::Enter(11), Event::Enter(12), Event::Leave(12), Event::Leave(11), Event::Leave(10),
+    ]);
+}
+
+#[quickcheck]
+fn clone_ref(node: Node) -> bool {
+    let iter = (&node).trans_iter();
+    let ids: Vec<_> = iter.clone().map(|n| n.id).collect();
+    iter.map(|n| n.id).eq(ids)
+}
+
+#[quickcheck]
+fn trans_iter_ref(node: Node) -> bool {
+    node.trans_iter_ref().eq(node.trans_iter())
+}
+
+#[test]
+fn clone_non_clone_rec_iter() {
+    /// Iterator over a single item which does not implement `Clone`
+    struct Single(Option<u8>);
+
+    impl Iterator for Single {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            self.0.take()
+        }
+    }
+
+    let iter = TransIter::new(0u8, |n| Single(Some(n + 1)).filter(|n| *n < 4)).depth_first();
+    assert_eq!(iter.clone().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(iter.into_trans_prio_queue().clone().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+}
+
+#[quickcheck]
+fn node_count_best_first(node: Node) -> bool {
+    let count = node.count();
+    (&node).best_first_with(|n| n.children.iter(), |n| n.children.len()).count() == count
+}
+
+#[quickcheck]
+fn best_first_order(node: Node) -> bool {
+    // Each yielded node must be one of the currently enqueued nodes with a
+    // maximal key. We keep track of the enqueued nodes and the number of nodes
+    // enqueued for each key.
+    let mut enqueued: std::collections::HashSet<_> = std::iter::once(&node as *const Node).collect();
+    let mut counts = [0usize; 8];
+    counts[(node.id % 8) as usize] += 1;
+
+    (&node).best_first_with(|n| n.children.iter(), |n| n.id % 8).all(|n| {
+        let key = (n.id % 8) as usize;
+        let max = counts.iter().rposition(|c| *c > 0);
+        let found = enqueued.remove(&(n as *const Node));
+        counts[key] -= 1;
+        n.children.iter().for_each(|c| {
+            enqueued.insert(c as *const Node);
+            counts[(c.id % 8) as usize] += 1;
+        });
+        found && max == Some(key)
+    })
+}
+
+#[test]
+fn best_first_reverse_key() {
+    use std::cmp::Reverse;
+
+    let items: Vec<_> = 1u8
+        .best_first_with(|n| if *n < 8 { vec![2 * n, 2 * n + 1] } else { Vec::new() }, |n| Reverse(*n))
+        .collect();
+    assert_eq!(items, (1..16).collect::<Vec<_>>());
+}
+
+#[quickcheck]
+fn prio_queue_filter_children(node: Node) -> bool {
+    let mut expected: Vec<_> = node.trans_iter().filter_children(|_, c| c.id & 1 == 0).map(|n| n.id).collect();
+    expected.sort_unstable();
+
+    let queue = node.trans_iter().depth_first().filter_children(|_, c| c.id & 1 == 0).into_trans_prio_queue();
+    let mut ids: Vec<_> = queue.map(|n| n.id).collect();
+    ids.sort_unstable();
+    ids == expected
+}
+
+#[test]
+fn prio_queue_guard_self_loops() {
+    let items: Vec<_> = 1u8
+        .trans_iter_with(|n| if *n < 4 { vec![*n, n + 1] } else { vec![*n] })
+        .guard_self_loops()
+        .into_trans_prio_queue()
+        .collect();
+    assert_eq!(items, vec![1, 2, 3, 4]);
+}
+
+#[quickcheck]
+fn stable_prio_queue_ties(node: Node) -> bool {
+    /// Wrapper which compares equal to any other
+    #[derive(Debug)]
+    struct Tie<'a>(&'a Node);
+
+    impl Ord for Tie<'_> {
+        fn cmp(&self, _: &Self) -> std::cmp::Ordering {
+            std::cmp::Ordering::Equal
+        }
     }
 
-    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        Box::new(self.children.clone().into_iter())
+    impl PartialOrd for Tie<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
     }
+
+    impl Eq for Tie<'_> {}
+
+    impl PartialEq for Tie<'_> {
+        fn eq(&self, _: &Self) -> bool {
+            true
+        }
+    }
+
+    // If all items compare equal, items are yielded in FIFO order
+    let ids = StablePrioQueue::new(Tie(&node), |Tie(n)| n.children.iter().map(Tie).collect::<Vec<_>>())
+        .map(|Tie(n)| n.id);
+    ids.eq(node.trans_iter().breadth_first().map(|n| n.id))
+}
+
+#[quickcheck]
+fn stable_prio_queue_count(node: Node) -> bool {
+    let count = node.count();
+    node.trans_prio_queue().stable().count() == count
+}
+
+#[test]
+fn stable_prio_queue_fifo() {
+    // Items are ordered by the first element only
+    #[derive(Debug, PartialEq, Eq)]
+    struct Item(u8, usize);
+
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    let items: Vec<_> = StablePrioQueue::new(Item(1, 0), |Item(p, i)| if *p > 0 {
+        (1..=64).map(|j| Item(p - 1, i + j)).collect()
+    } else {
+        Vec::new()
+    }).map(|Item(_, i)| i).collect();
+    assert_eq!(items, (0..65).collect::<Vec<_>>());
+}
+
+#[quickcheck]
+fn prio_queue_from_heap(initial: Vec<u8>) -> bool {
+    let recursion = |n: &u8| if *n > 1 { vec![n / 2, n / 3] } else { Vec::new() };
+    let heap = initial.iter().cloned().collect();
+    TransPrioQueue::from_heap(heap, recursion).eq(TransPrioQueue::new_multi(initial, recursion))
+}
+
+#[quickcheck]
+fn min_reachable(node: Node) -> bool {
+    let min = node.trans_prio_queue().min();
+    node.trans_prio_queue().min_reachable() == min
+}
+
+#[quickcheck]
+fn min_reachable_last(initial: Vec<u8>) -> bool {
+    let recursion = |n: &u8| if *n > 1 { vec![n / 2, n / 3] } else { Vec::new() };
+    let last = TransPrioQueue::new_multi(initial.clone(), recursion).last();
+    TransPrioQueue::new_multi(initial, recursion).min_reachable() == last
 }
 